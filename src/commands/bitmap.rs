@@ -1,9 +1,11 @@
 use crate::{
     command_code::{CommandCode, InvalidCommandCodeError},
-    commands::errors::{TryFromPacketError, TryIntoPacketError},
+    commands::errors::{
+        TryFromPacketError, TryFromWindowError, TryIntoPacketError,
+    },
     compression::{compress, decompress, CompressionError},
     Bitmap, CompressionCode, DataRef, Grid, Header, Origin, Packet, Pixels,
-    TypedCommand, TILE_SIZE,
+    TypedCommand, Window, TILE_SIZE,
 };
 
 /// Overwrites a rectangular region of pixels.
@@ -139,6 +141,43 @@ impl From<Bitmap> for BitmapCommand {
     }
 }
 
+impl<T: Grid<bool>> TryFrom<&Window<'_, bool, T>> for BitmapCommand {
+    type Error = TryFromWindowError;
+
+    /// Copies the pixels of the window into a command that draws them at the same position.
+    ///
+    /// The position of the window in the underlying grid is used as the origin,
+    /// also for windows created from a [`crate::WindowMut`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use servicepoint::*;
+    /// # let connection = FakeConnection;
+    /// let mut canvas = Bitmap::max_sized();
+    /// canvas.window_mut(16..32, 8..24).unwrap().fill(true);
+    ///
+    /// // only send the changed part
+    /// let window = canvas.window(16..32, 8..24).unwrap();
+    /// let command = BitmapCommand::try_from(&window).unwrap();
+    /// assert_eq!(command.origin, Origin::new(16, 8));
+    /// connection.send_command(command).unwrap();
+    /// ```
+    fn try_from(value: &Window<'_, bool, T>) -> Result<Self, Self::Error> {
+        let origin = Origin::new(value.xs().start, value.ys().start);
+        if origin.x % TILE_SIZE != 0 {
+            return Err(TryFromWindowError::UnalignedOrigin(origin.x));
+        }
+        let bitmap = Bitmap::try_from(value)
+            .map_err(|()| TryFromWindowError::UnalignedWidth(value.width()))?;
+        Ok(Self {
+            bitmap,
+            origin,
+            compression: CompressionCode::default(),
+        })
+    }
+}
+
 impl BitmapCommand {
     fn command_code_for_compression(
         compression_code: CompressionCode,
@@ -339,4 +378,38 @@ mod tests {
             Err(TryIntoPacketError::ConversionError(_)),
         ))
     }
+
+    #[test]
+    fn from_window() {
+        let mut canvas = Bitmap::new(32, 4).unwrap();
+        canvas.set(9, 2, true);
+
+        let window = canvas.window(8..24, 1..3).unwrap();
+        let command = BitmapCommand::try_from(&window).unwrap();
+        assert_eq!(command.origin, Origin::new(8, 1));
+        assert_eq!(command.bitmap.width(), 16);
+        assert_eq!(command.bitmap.data_ref(), [0, 0, 0x40, 0]);
+
+        let window = canvas.window(4..12, 0..4).unwrap();
+        assert_eq!(
+            BitmapCommand::try_from(&window),
+            Err(TryFromWindowError::UnalignedOrigin(4))
+        );
+        let window = canvas.window(8..12, 0..4).unwrap();
+        assert_eq!(
+            BitmapCommand::try_from(&window),
+            Err(TryFromWindowError::UnalignedWidth(4))
+        );
+    }
+
+    #[test]
+    fn from_nested_window() {
+        let mut canvas = Bitmap::max_sized();
+        let outer = canvas.window_mut(16..64, 8..16).unwrap();
+        let window = outer.window(8..16, 0..8).unwrap();
+        let command = BitmapCommand::try_from(&window).unwrap();
+        assert_eq!(command.origin, Origin::new(24, 8));
+        assert_eq!(command.bitmap.width(), 8);
+        assert_eq!(command.bitmap.height(), 8);
+    }
 }
@@ -7,6 +7,8 @@ use crate::{
 };
 
 /// Binary operations for use with the [`BitVecCommand`] command.
+///
+/// The same operations can be applied locally, e.g. with [`crate::BoolGridMutExt::blit_with`].
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Hash)]
 #[repr(u8)]
 pub enum BinaryOperation {
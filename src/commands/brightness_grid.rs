@@ -1,7 +1,8 @@
 use crate::{
     command_code::CommandCode, commands::check_command_code,
-    commands::errors::TryFromPacketError, BrightnessGrid, ByteGrid, Header,
-    Origin, Packet, Tiles, TryIntoPacketError, TypedCommand,
+    commands::errors::TryFromPacketError, Brightness, BrightnessGrid, ByteGrid,
+    Grid, Header, Origin, Packet, Tiles, TryIntoPacketError, TypedCommand,
+    Window,
 };
 
 /// Set the brightness of individual tiles in a rectangular area of the display.
@@ -46,6 +47,21 @@ impl From<BrightnessGrid> for BrightnessGridCommand {
     }
 }
 
+impl<T: Grid<Brightness>> From<&Window<'_, Brightness, T>>
+    for BrightnessGridCommand
+{
+    /// Copies the cells of the window into a command that draws them at the same position.
+    ///
+    /// The position of the window in the underlying grid is used as the origin,
+    /// also for windows created from a [`crate::WindowMut`].
+    fn from(value: &Window<'_, Brightness, T>) -> Self {
+        Self {
+            grid: BrightnessGrid::from(value),
+            origin: Origin::new(value.xs().start, value.ys().start),
+        }
+    }
+}
+
 impl TryFrom<Packet> for BrightnessGridCommand {
     type Error = TryFromPacketError;
 
@@ -108,8 +124,8 @@ impl From<BrightnessGridCommand> for TypedCommand {
 mod tests {
     use crate::{
         commands::{errors::TryFromPacketError, tests::TestImplementsCommand},
-        Brightness, BrightnessGrid, BrightnessGridCommand, Origin, Packet,
-        TypedCommand,
+        Brightness, BrightnessGrid, BrightnessGridCommand, Grid, GridMut,
+        Origin, Packet, TypedCommand,
     };
 
     impl TestImplementsCommand for BrightnessGridCommand {}
@@ -198,4 +214,27 @@ mod tests {
             BrightnessGridCommand::try_from(packet)
         );
     }
+
+    #[test]
+    fn from_window() {
+        let mut grid = BrightnessGrid::new(4, 4);
+        grid.set(3, 2, Brightness::MAX);
+        let window = grid.window(2..4, 1..4).unwrap();
+        let command = BrightnessGridCommand::from(&window);
+        assert_eq!(command.origin, Origin::new(2, 1));
+        assert_eq!(command.grid.get(1, 1), Brightness::MAX);
+        assert_eq!(command.grid.height(), 3);
+    }
+
+    #[test]
+    fn from_nested_window() {
+        let mut grid = BrightnessGrid::new(4, 4);
+        grid.set(3, 2, Brightness::MIN);
+        let outer = grid.window_mut(2..4, 1..4).unwrap();
+        let window = outer.window(1..2, 1..3).unwrap();
+        let command = BrightnessGridCommand::from(&window);
+        assert_eq!(command.origin, Origin::new(3, 2));
+        assert_eq!(command.grid.get(0, 0), Brightness::MIN);
+        assert_eq!(command.grid.height(), 2);
+    }
 }
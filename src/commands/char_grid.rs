@@ -1,7 +1,7 @@
 use crate::{
     command_code::CommandCode, commands::check_command_code,
-    commands::errors::TryFromPacketError, CharGrid, Header, Origin, Packet,
-    Tiles, TryIntoPacketError, TypedCommand,
+    commands::errors::TryFromPacketError, CharGrid, Grid, Header, Origin,
+    Packet, Tiles, TryIntoPacketError, TypedCommand, Window,
 };
 
 /// Show text on the screen.
@@ -111,6 +111,19 @@ impl From<CharGrid> for CharGridCommand {
     }
 }
 
+impl<T: Grid<char>> From<&Window<'_, char, T>> for CharGridCommand {
+    /// Copies the cells of the window into a command that draws them at the same position.
+    ///
+    /// The position of the window in the underlying grid is used as the origin,
+    /// also for windows created from a [`crate::WindowMut`].
+    fn from(value: &Window<'_, char, T>) -> Self {
+        Self {
+            grid: CharGrid::from(value),
+            origin: Origin::new(value.xs().start, value.ys().start),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -189,4 +202,23 @@ mod tests {
             CharGridCommand::try_from(packet)
         );
     }
+
+    #[test]
+    fn from_window() {
+        let grid = CharGrid::from("abc\ndef\nghi");
+        let window = grid.window(1..3, 1..3).unwrap();
+        let command = CharGridCommand::from(&window);
+        assert_eq!(command.origin, Origin::new(1, 1));
+        assert_eq!(command.grid, CharGrid::from("ef\nhi"));
+    }
+
+    #[test]
+    fn from_nested_window() {
+        let mut grid = CharGrid::from("abc\ndef\nghi");
+        let outer = grid.window_mut(1..3, 1..3).unwrap();
+        let window = outer.window(1..2, 0..2).unwrap();
+        let command = CharGridCommand::from(&window);
+        assert_eq!(command.origin, Origin::new(2, 1));
+        assert_eq!(command.grid, CharGrid::from("f\ni"));
+    }
 }
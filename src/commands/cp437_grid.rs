@@ -1,7 +1,7 @@
 use crate::{
     command_code::CommandCode, commands::check_command_code,
-    commands::errors::TryFromPacketError, Cp437Grid, Header, Origin, Packet,
-    Tiles, TryIntoPacketError, TypedCommand,
+    commands::errors::TryFromPacketError, Cp437Grid, Grid, Header, Origin,
+    Packet, Tiles, TryIntoPacketError, TypedCommand, Window,
 };
 
 /// Show text on the screen.
@@ -122,6 +122,19 @@ impl From<Cp437Grid> for Cp437GridCommand {
     }
 }
 
+impl<T: Grid<u8>> From<&Window<'_, u8, T>> for Cp437GridCommand {
+    /// Copies the cells of the window into a command that draws them at the same position.
+    ///
+    /// The position of the window in the underlying grid is used as the origin,
+    /// also for windows created from a [`crate::WindowMut`].
+    fn from(value: &Window<'_, u8, T>) -> Self {
+        Self {
+            grid: Cp437Grid::from(value),
+            origin: Origin::new(value.xs().start, value.ys().start),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -197,4 +210,23 @@ mod tests {
             Cp437GridCommand::try_from(packet)
         );
     }
+
+    #[test]
+    fn from_window() {
+        let grid = Cp437Grid::load(3, 2, &[1, 2, 3, 4, 5, 6]).unwrap();
+        let window = grid.window(2..3, 0..2).unwrap();
+        let command = Cp437GridCommand::from(&window);
+        assert_eq!(command.origin, Origin::new(2, 0));
+        assert_eq!(command.grid, Cp437Grid::load(1, 2, &[3, 6]).unwrap());
+    }
+
+    #[test]
+    fn from_nested_window() {
+        let mut grid = Cp437Grid::load(3, 2, &[1, 2, 3, 4, 5, 6]).unwrap();
+        let outer = grid.window_mut(1..3, 0..2).unwrap();
+        let window = outer.window(1..2, 1..2).unwrap();
+        let command = Cp437GridCommand::from(&window);
+        assert_eq!(command.origin, Origin::new(2, 1));
+        assert_eq!(command.grid, Cp437Grid::load(1, 1, &[6]).unwrap());
+    }
 }
@@ -42,6 +42,17 @@ pub enum TryFromPacketError {
     LoadBitmapFailed(#[from] LoadBitmapError),
 }
 
+/// Err values for creating a [`crate::BitmapCommand`] from a [`crate::Window`].
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum TryFromWindowError {
+    /// The window does not start on a tile boundary.
+    #[error("The window starts at x={0}, which is not a multiple of 8")]
+    UnalignedOrigin(usize),
+    /// The width of the window is not a multiple of 8.
+    #[error("The window has a width of {0}, which is not a multiple of 8")]
+    UnalignedWidth(usize),
+}
+
 /// An error that can occur when parsing a raw packet as a command
 #[derive(Debug, PartialEq, thiserror::Error)]
 pub enum TryIntoPacketError {
@@ -0,0 +1,350 @@
+use crate::{
+    containers::ClippedArea, BinaryOperation, Bitmap, Grid, GridDiff, GridMut,
+    Origin, Pixels, Region,
+};
+
+/// One layer of a [Compositor].
+///
+/// The fields can be changed freely, the effect is visible on the next [`Compositor::flatten`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layer {
+    /// the content of the layer
+    pub bitmap: Bitmap,
+    /// which pixels of `bitmap` are drawn, has to be at least as big as `bitmap`.
+    ///
+    /// When not set, all pixels are drawn.
+    pub mask: Option<Bitmap>,
+    /// position of the left edge of the layer, can be negative
+    pub x: isize,
+    /// position of the top edge of the layer, can be negative
+    pub y: isize,
+    /// invisible layers are skipped
+    pub visible: bool,
+    /// how the pixels of this layer are combined with the layers below
+    pub blend: BinaryOperation,
+}
+
+impl Layer {
+    /// Creates a visible layer at the top left that overwrites everything below it.
+    #[must_use]
+    pub fn new(bitmap: Bitmap) -> Self {
+        Self {
+            bitmap,
+            mask: None,
+            x: 0,
+            y: 0,
+            visible: true,
+            blend: BinaryOperation::Overwrite,
+        }
+    }
+
+    fn draw(&self, target: &mut Bitmap) {
+        let Some(mask) = &self.mask else {
+            target.blit_bitmap(&self.bitmap, self.x, self.y, self.blend);
+            return;
+        };
+        if self.blend == BinaryOperation::Overwrite {
+            target.blit_bitmap_masked(&self.bitmap, mask, self.x, self.y);
+            return;
+        }
+
+        assert!(
+            mask.width() >= self.bitmap.width()
+                && mask.height() >= self.bitmap.height(),
+            "mask is too small for layer"
+        );
+        let Some(area) = ClippedArea::new(target, &self.bitmap, self.x, self.y)
+        else {
+            return;
+        };
+        for (source_x, source_y, target_x, target_y) in area.positions() {
+            if mask.get(source_x, source_y) {
+                let old = target.get(target_x, target_y);
+                let new = self.bitmap.get(source_x, source_y);
+                target.set(target_x, target_y, self.blend.apply(old, new));
+            }
+        }
+    }
+}
+
+impl From<Bitmap> for Layer {
+    fn from(value: Bitmap) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Stacks multiple [Layer]s into one [Bitmap].
+///
+/// Layers are drawn in order, the first layer is at the bottom.
+/// Pixels not covered by any layer are off.
+///
+/// # Examples
+///
+/// ```rust
+/// # use servicepoint::*;
+/// # let connection = FakeConnection;
+/// let mut compositor = Compositor::max_sized();
+///
+/// let mut background = Bitmap::max_sized();
+/// background.fill(true);
+/// compositor.push(Layer::new(background));
+///
+/// let mut notification = Layer::new(Bitmap::new(64, 16).unwrap());
+/// notification.x = 100;
+/// notification.y = 40;
+/// let notification = compositor.push(notification);
+///
+/// let frame = compositor.flatten();
+/// connection.send_command(BitmapCommand::from(frame.clone())).unwrap();
+///
+/// // hide the notification again
+/// compositor.layer_mut(notification).unwrap().visible = false;
+/// compositor.flatten();
+/// assert_eq!(compositor.changed_regions().len(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Compositor {
+    layers: Vec<Layer>,
+    frame: Bitmap,
+    changed: Vec<Region<Pixels>>,
+    invalidated: bool,
+}
+
+impl Compositor {
+    /// Creates a compositor without layers that produces frames of the specified size.
+    ///
+    /// returns: None if the width is not a multiple of 8
+    #[must_use]
+    pub fn new(width: usize, height: usize) -> Option<Self> {
+        Some(Self {
+            layers: Vec::new(),
+            frame: Bitmap::new(width, height)?,
+            changed: Vec::new(),
+            invalidated: true,
+        })
+    }
+
+    /// Creates a compositor without layers that produces frames the size of the screen.
+    #[must_use]
+    pub fn max_sized() -> Self {
+        Self {
+            layers: Vec::new(),
+            frame: Bitmap::max_sized(),
+            changed: Vec::new(),
+            invalidated: true,
+        }
+    }
+
+    /// Adds a layer on top of all other layers.
+    ///
+    /// returns: the index of the new layer
+    pub fn push(&mut self, layer: Layer) -> usize {
+        self.layers.push(layer);
+        self.layers.len() - 1
+    }
+
+    /// Inserts a layer at the specified index, moving all layers above it up by one.
+    ///
+    /// # Panics
+    ///
+    /// When `index` is greater than the number of layers.
+    pub fn insert(&mut self, index: usize, layer: Layer) {
+        self.layers.insert(index, layer);
+    }
+
+    /// Removes the layer at the specified index, moving all layers above it down by one.
+    ///
+    /// returns: None if there is no layer at the index
+    pub fn remove(&mut self, index: usize) -> Option<Layer> {
+        if index < self.layers.len() {
+            Some(self.layers.remove(index))
+        } else {
+            None
+        }
+    }
+
+    /// Get the layer at the specified index.
+    #[must_use]
+    pub fn layer(&self, index: usize) -> Option<&Layer> {
+        self.layers.get(index)
+    }
+
+    /// Get the layer at the specified index for modification.
+    #[must_use]
+    pub fn layer_mut(&mut self, index: usize) -> Option<&mut Layer> {
+        self.layers.get_mut(index)
+    }
+
+    /// All layers, from bottom to top.
+    #[must_use]
+    pub fn layers(&self) -> &[Layer] {
+        &self.layers
+    }
+
+    /// All layers for modification, from bottom to top.
+    ///
+    /// This can be used to re-order the layers.
+    #[must_use]
+    pub fn layers_mut(&mut self) -> &mut [Layer] {
+        &mut self.layers
+    }
+
+    /// Marks the whole frame as changed on the next call to [`Self::flatten`].
+    ///
+    /// Use this when the content of the display is not known anymore, e.g. after sending a [`crate::ClearCommand`].
+    pub fn invalidate(&mut self) {
+        self.invalidated = true;
+    }
+
+    /// Draws all visible layers into a new frame and tracks the changes to the previous frame.
+    ///
+    /// The changes can be retrieved with [`Self::changed_regions`] afterward.
+    pub fn flatten(&mut self) -> &Bitmap {
+        let mut frame = self.frame.clone();
+        frame.fill(false);
+        for layer in self.layers.iter().filter(|layer| layer.visible) {
+            layer.draw(&mut frame);
+        }
+
+        self.changed = if self.invalidated {
+            vec![Region::new(Origin::ZERO, frame.width(), frame.height())]
+        } else {
+            #[allow(
+                clippy::unwrap_used,
+                reason = "the frame never changes its size"
+            )]
+            GridDiff::bitmaps(&self.frame, &frame)
+                .unwrap()
+                .tile_regions(0)
+        };
+        self.invalidated = false;
+        self.frame = frame;
+        &self.frame
+    }
+
+    /// The result of the last call to [`Self::flatten`].
+    #[must_use]
+    pub fn frame(&self) -> &Bitmap {
+        &self.frame
+    }
+
+    /// The parts of the frame that changed in the last call to [`Self::flatten`].
+    ///
+    /// Regions start and end on tile boundaries (or the bottom edge of the frame),
+    /// so each of them can be sent as a [`crate::BitmapCommand`].
+    /// On the first call or after [`Self::invalidate`], the whole frame is reported.
+    #[must_use]
+    pub fn changed_regions(&self) -> &[Region<Pixels>] {
+        &self.changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        BinaryOperation, Bitmap, Compositor, DataRef, Grid, GridMut, Layer,
+        Origin, Region,
+    };
+
+    fn filled(width: usize, height: usize) -> Bitmap {
+        let mut bitmap = Bitmap::new(width, height).unwrap();
+        bitmap.fill(true);
+        bitmap
+    }
+
+    #[test]
+    fn stacking_order() {
+        let mut compositor = Compositor::new(16, 8).unwrap();
+        compositor.push(Layer::new(filled(16, 8)));
+        let mut hole = Layer::new(Bitmap::new(8, 8).unwrap());
+        hole.x = 4;
+        compositor.push(hole);
+
+        let frame = compositor.flatten();
+        assert!(frame.get(3, 0));
+        assert!(!frame.get(4, 0));
+        assert!(!frame.get(11, 7));
+        assert!(frame.get(12, 7));
+
+        compositor.layers_mut().swap(0, 1);
+        assert!(compositor.flatten().all());
+    }
+
+    #[test]
+    fn visibility_and_offsets() {
+        let mut compositor = Compositor::new(8, 8).unwrap();
+        let mut layer = Layer::new(filled(8, 8));
+        layer.x = -6;
+        layer.y = 6;
+        let index = compositor.push(layer);
+        assert_eq!(compositor.flatten().count_ones(), 4);
+
+        compositor.layer_mut(index).unwrap().visible = false;
+        assert!(!compositor.flatten().any());
+        assert!(compositor.remove(index).is_some());
+        assert!(compositor.remove(index).is_none());
+    }
+
+    #[test]
+    fn blend_and_mask() {
+        let mut compositor = Compositor::new(8, 1).unwrap();
+        compositor
+            .push(Layer::new(Bitmap::load(8, 1, &[0b1111_0000]).unwrap()));
+
+        let mut mask = Bitmap::new(16, 2).unwrap();
+        mask.set(0, 0, true);
+        mask.set(5, 0, true);
+        let mut xor = Layer::new(filled(8, 1));
+        xor.mask = Some(mask.clone());
+        xor.blend = BinaryOperation::Xor;
+        compositor.push(xor);
+        assert_eq!(compositor.flatten().data_ref(), [0b0111_0100]);
+
+        let mut overwrite = Layer::new(Bitmap::new(8, 1).unwrap());
+        overwrite.mask = Some(mask);
+        compositor.insert(1, overwrite);
+        assert_eq!(compositor.flatten().data_ref(), [0b1111_0100]);
+    }
+
+    #[test]
+    fn tracks_changes() {
+        let mut compositor = Compositor::new(32, 20).unwrap();
+        compositor.flatten();
+        assert_eq!(
+            compositor.changed_regions(),
+            [Region::new(Origin::ZERO, 32, 20)]
+        );
+
+        compositor.flatten();
+        assert!(compositor.changed_regions().is_empty());
+
+        let mut layer = Layer::new(filled(8, 12));
+        layer.x = 4;
+        layer.y = 2;
+        let index = compositor.push(layer);
+        compositor.flatten();
+        assert_eq!(
+            compositor.changed_regions(),
+            [Region::new(Origin::ZERO, 16, 16)]
+        );
+
+        let layer = compositor.layer_mut(index).unwrap();
+        layer.x = 24;
+        layer.y = 17;
+        compositor.flatten();
+        assert_eq!(
+            compositor.changed_regions(),
+            [
+                Region::new(Origin::ZERO, 16, 16),
+                Region::new(Origin::new(24, 16), 8, 4),
+            ]
+        );
+
+        compositor.invalidate();
+        compositor.flatten();
+        assert_eq!(
+            compositor.changed_regions(),
+            [Region::new(Origin::ZERO, 32, 20)]
+        );
+    }
+}
@@ -20,6 +20,23 @@ impl UdpSocketExt for UdpSocket {
 
     fn send_command(&self, command: impl TryInto<Packet>) -> Option<()> {
         let packet = command.try_into().ok()?;
+        self.send_packet(packet)
+    }
+}
+
+/// Something packets can be sent through, e.g. a [`UdpSocket`] or a [`FakeConnection`].
+///
+/// This allows sending from code that does not know about the specific kind of connection,
+/// like [`crate::Display::flush`].
+pub trait Connection {
+    /// Sends the serialized packet.
+    ///
+    /// returns: None if sending failed
+    fn send_packet(&self, packet: Packet) -> Option<()>;
+}
+
+impl Connection for UdpSocket {
+    fn send_packet(&self, packet: Packet) -> Option<()> {
         let vec: Vec<_> = packet.into();
         self.send(&vec).ok()?;
         Some(())
@@ -32,9 +49,47 @@ pub struct FakeConnection;
 impl FakeConnection {
     /// Serializes the command, but does not actually send it as this is the fake connection
     pub fn send_command(&self, command: impl TryInto<Packet>) -> Option<()> {
-        _ = self; // suppress unused warning
         let packet = command.try_into().ok()?;
+        self.send_packet(packet)
+    }
+}
+
+impl Connection for FakeConnection {
+    fn send_packet(&self, packet: Packet) -> Option<()> {
+        _ = self; // suppress unused warning
         drop(Vec::from(packet));
         Some(())
     }
 }
+
+/// A connection for tests that keeps all packets sent through it.
+#[cfg(test)]
+#[derive(Default)]
+pub(crate) struct RecordingConnection(std::cell::RefCell<Vec<Packet>>);
+
+#[cfg(test)]
+impl RecordingConnection {
+    /// The packets sent so far, oldest first.
+    pub(crate) fn packets(&self) -> std::cell::Ref<'_, Vec<Packet>> {
+        self.0.borrow()
+    }
+}
+
+#[cfg(test)]
+impl Connection for RecordingConnection {
+    fn send_packet(&self, packet: Packet) -> Option<()> {
+        self.0.borrow_mut().push(packet);
+        Some(())
+    }
+}
+
+/// A connection for tests where sending always fails.
+#[cfg(test)]
+pub(crate) struct FailingConnection;
+
+#[cfg(test)]
+impl Connection for FailingConnection {
+    fn send_packet(&self, _: Packet) -> Option<()> {
+        None
+    }
+}
@@ -3,7 +3,7 @@ use crate::{
     GridMut, Payload, ValueGrid, Window, WindowMut, PIXEL_HEIGHT, PIXEL_WIDTH,
 };
 use ::bitvec::{order::Msb0, prelude::BitSlice, slice::IterMut};
-use std::ops::RangeBounds;
+use std::ops::{Range, RangeBounds};
 
 /// A fixed-size 2D grid of booleans.
 ///
@@ -203,6 +203,36 @@ impl Bitmap {
         let ys = absolute_bounds_to_abs_range(ys, self.height)?;
         WindowMut::new(self, xs, ys)
     }
+
+    /// All pixels as one continuous bit slice.
+    pub(crate) fn bits(&self) -> &BitSlice<u8, Msb0> {
+        &self.bit_vec
+    }
+
+    /// All pixels as one continuous mutable bit slice.
+    pub(crate) fn bits_mut(&mut self) -> &mut BitSlice<u8, Msb0> {
+        &mut self.bit_vec
+    }
+
+    /// The pixels in row `y` in the columns `xs`.
+    pub(crate) fn row_bits(
+        &self,
+        y: usize,
+        xs: Range<usize>,
+    ) -> &BitSlice<u8, Msb0> {
+        let start = y * self.width;
+        &self.bit_vec[start + xs.start..start + xs.end]
+    }
+
+    /// The pixels in row `y` in the columns `xs`, writable.
+    pub(crate) fn row_bits_mut(
+        &mut self,
+        y: usize,
+        xs: Range<usize>,
+    ) -> &mut BitSlice<u8, Msb0> {
+        let start = y * self.width;
+        &mut self.bit_vec[start + xs.start..start + xs.end]
+    }
 }
 
 impl Grid<bool> for Bitmap {
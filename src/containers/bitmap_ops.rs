@@ -0,0 +1,326 @@
+use crate::{Bitmap, Grid, Window, WindowMut};
+use std::ops::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not,
+};
+
+fn assert_same_size(left: &impl Grid<bool>, right: &impl Grid<bool>) {
+    assert_eq!(
+        (left.width(), left.height()),
+        (right.width(), right.height()),
+        "Cannot combine grids of different sizes"
+    );
+}
+
+macro_rules! impl_bitmap_op {
+    ($op:ident, $op_fn:ident, $op_assign:ident, $op_assign_fn:ident, $operator:tt) => {
+        impl $op_assign<&Bitmap> for Bitmap {
+            /// Combines all pixels of both bitmaps in place.
+            ///
+            /// This works on whole words of the underlying [`crate::DisplayBitVec`].
+            /// It is the local equivalent of sending a [`crate::BitVecCommand`] with a [`crate::BinaryOperation`].
+            ///
+            /// # Panics
+            ///
+            /// When the bitmaps are not of the same size.
+            fn $op_assign_fn(&mut self, rhs: &Bitmap) {
+                assert_same_size(self, rhs);
+                *self.bits_mut() $operator rhs.bits();
+            }
+        }
+
+        impl $op_assign<Bitmap> for Bitmap {
+            fn $op_assign_fn(&mut self, rhs: Bitmap) {
+                *self $operator &rhs;
+            }
+        }
+
+        impl $op<&Bitmap> for Bitmap {
+            type Output = Bitmap;
+
+            fn $op_fn(mut self, rhs: &Bitmap) -> Self::Output {
+                self $operator rhs;
+                self
+            }
+        }
+
+        impl $op<Bitmap> for Bitmap {
+            type Output = Bitmap;
+
+            fn $op_fn(mut self, rhs: Bitmap) -> Self::Output {
+                self $operator &rhs;
+                self
+            }
+        }
+
+        impl $op<&Bitmap> for &Bitmap {
+            type Output = Bitmap;
+
+            fn $op_fn(self, rhs: &Bitmap) -> Self::Output {
+                let mut result = self.clone();
+                result $operator rhs;
+                result
+            }
+        }
+
+        impl $op_assign<&Window<'_, bool, Bitmap>>
+            for WindowMut<'_, bool, Bitmap>
+        {
+            /// Combines all pixels of both windows in place.
+            ///
+            /// # Panics
+            ///
+            /// When the windows are not of the same size.
+            fn $op_assign_fn(&mut self, rhs: &Window<'_, bool, Bitmap>) {
+                assert_same_size(self, rhs);
+                let (xs, ys) = (self.xs(), self.ys());
+                let (rhs_xs, rhs_ys) = (rhs.xs(), rhs.ys());
+                for (y, rhs_y) in ys.zip(rhs_ys) {
+                    // the window may be split from the same bitmap,
+                    // so the row has to be copied before borrowing self mutably
+                    let rhs_row = rhs.with_grid(|grid| {
+                        grid.row_bits(rhs_y, rhs_xs.clone()).to_bitvec()
+                    });
+                    self.with_grid_mut(|grid| {
+                        *grid.row_bits_mut(y, xs.clone()) $operator
+                            rhs_row.as_bitslice();
+                    });
+                }
+            }
+        }
+
+        impl $op_assign<&WindowMut<'_, bool, Bitmap>>
+            for WindowMut<'_, bool, Bitmap>
+        {
+            /// Combines all pixels of both windows in place.
+            ///
+            /// # Panics
+            ///
+            /// When the windows are not of the same size.
+            fn $op_assign_fn(&mut self, rhs: &WindowMut<'_, bool, Bitmap>) {
+                assert_same_size(self, rhs);
+                let (xs, ys) = (self.xs(), self.ys());
+                let (rhs_xs, rhs_ys) = (rhs.xs(), rhs.ys());
+                for (y, rhs_y) in ys.zip(rhs_ys) {
+                    // both windows may be split from the same bitmap,
+                    // so the row has to be copied before borrowing self mutably
+                    let rhs_row = rhs.with_grid(|grid| {
+                        grid.row_bits(rhs_y, rhs_xs.clone()).to_bitvec()
+                    });
+                    self.with_grid_mut(|grid| {
+                        *grid.row_bits_mut(y, xs.clone()) $operator
+                            rhs_row.as_bitslice();
+                    });
+                }
+            }
+        }
+
+        impl $op_assign<&Bitmap> for WindowMut<'_, bool, Bitmap> {
+            /// Combines all pixels of the window with the bitmap in place.
+            ///
+            /// # Panics
+            ///
+            /// When the window and bitmap are not of the same size.
+            fn $op_assign_fn(&mut self, rhs: &Bitmap) {
+                assert_same_size(self, rhs);
+                let (xs, ys) = (self.xs(), self.ys());
+                self.with_grid_mut(|grid| {
+                    for (y, rhs_y) in ys.zip(0..rhs.height()) {
+                        *grid.row_bits_mut(y, xs.clone()) $operator
+                            rhs.row_bits(rhs_y, 0..rhs.width());
+                    }
+                });
+            }
+        }
+    };
+}
+
+impl_bitmap_op!(BitAnd, bitand, BitAndAssign, bitand_assign, &=);
+impl_bitmap_op!(BitOr, bitor, BitOrAssign, bitor_assign, |=);
+impl_bitmap_op!(BitXor, bitxor, BitXorAssign, bitxor_assign, ^=);
+
+impl Not for Bitmap {
+    type Output = Bitmap;
+
+    fn not(mut self) -> Self::Output {
+        self.invert();
+        self
+    }
+}
+
+impl Not for &Bitmap {
+    type Output = Bitmap;
+
+    fn not(self) -> Self::Output {
+        !self.clone()
+    }
+}
+
+impl Bitmap {
+    /// Inverts all pixels in place.
+    pub fn invert(&mut self) {
+        _ = !self.bits_mut();
+    }
+
+    /// Counts the pixels that are on.
+    #[must_use]
+    pub fn count_ones(&self) -> usize {
+        self.bits().count_ones()
+    }
+
+    /// Checks whether any pixel is on.
+    #[must_use]
+    pub fn any(&self) -> bool {
+        self.bits().any()
+    }
+
+    /// Checks whether all pixels are on.
+    ///
+    /// Returns true for empty bitmaps.
+    #[must_use]
+    pub fn all(&self) -> bool {
+        self.bits().all()
+    }
+}
+
+macro_rules! impl_window_queries {
+    ($window:ident) => {
+        impl $window<'_, bool, Bitmap> {
+            /// Counts the pixels in the window that are on.
+            #[must_use]
+            pub fn count_ones(&self) -> usize {
+                let (xs, ys) = (self.xs(), self.ys());
+                self.with_grid(|grid| {
+                    ys.map(|y| grid.row_bits(y, xs.clone()).count_ones()).sum()
+                })
+            }
+
+            /// Checks whether any pixel in the window is on.
+            #[must_use]
+            pub fn any(&self) -> bool {
+                let (xs, mut ys) = (self.xs(), self.ys());
+                self.with_grid(|grid| {
+                    ys.any(|y| grid.row_bits(y, xs.clone()).any())
+                })
+            }
+
+            /// Checks whether all pixels in the window are on.
+            ///
+            /// Returns true for empty windows.
+            #[must_use]
+            pub fn all(&self) -> bool {
+                let (xs, mut ys) = (self.xs(), self.ys());
+                self.with_grid(|grid| {
+                    ys.all(|y| grid.row_bits(y, xs.clone()).all())
+                })
+            }
+        }
+    };
+}
+
+impl_window_queries!(Window);
+impl_window_queries!(WindowMut);
+
+impl WindowMut<'_, bool, Bitmap> {
+    /// Inverts all pixels in the window in place.
+    pub fn invert(&mut self) {
+        let (xs, ys) = (self.xs(), self.ys());
+        self.with_grid_mut(|grid| {
+            for y in ys {
+                _ = !grid.row_bits_mut(y, xs.clone());
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Bitmap, DataRef, Grid, GridMut};
+
+    #[test]
+    fn bitmap_ops() {
+        let a = Bitmap::load(8, 2, &[0b1100_1100, 0xFF]).unwrap();
+        let b = Bitmap::load(8, 2, &[0b1010_1010, 0x00]).unwrap();
+
+        assert_eq!((&a & &b).data_ref(), [0b1000_1000, 0x00]);
+        assert_eq!((&a | &b).data_ref(), [0b1110_1110, 0xFF]);
+        assert_eq!((&a ^ &b).data_ref(), [0b0110_0110, 0xFF]);
+        assert_eq!((!&a).data_ref(), [0b0011_0011, 0x00]);
+        assert_eq!((a.clone() ^ b.clone()).data_ref(), (&a ^ &b).data_ref());
+
+        let mut c = a.clone();
+        c &= b;
+        c |= &a;
+        assert_eq!(c, a);
+    }
+
+    #[test]
+    #[should_panic]
+    fn different_sizes() {
+        let mut a = Bitmap::new(8, 2).unwrap();
+        a ^= Bitmap::new(16, 2).unwrap();
+    }
+
+    #[test]
+    fn queries() {
+        let mut bitmap = Bitmap::new(16, 3).unwrap();
+        assert!(!bitmap.any());
+        assert!(!bitmap.all());
+        assert_eq!(bitmap.count_ones(), 0);
+
+        bitmap.set(3, 1, true);
+        assert!(bitmap.any());
+        assert_eq!(bitmap.count_ones(), 1);
+
+        bitmap.invert();
+        assert_eq!(bitmap.count_ones(), 47);
+        bitmap.fill(true);
+        assert!(bitmap.all());
+    }
+
+    #[test]
+    fn window_ops() {
+        let mut target = Bitmap::new(16, 4).unwrap();
+        let mut source = Bitmap::new(16, 4).unwrap();
+        source.fill(true);
+
+        {
+            let mut window = target.window_mut(3..8, 1..3).unwrap();
+            window |= &source.window(5..10, 0..2).unwrap();
+            assert!(window.all());
+            assert_eq!(window.count_ones(), 10);
+            window.invert();
+            assert!(!window.any());
+            window ^= &source.window_mut(0..5, 2..4).unwrap();
+        }
+        assert_eq!(target.data_ref(), [0, 0, 0x1F, 0, 0x1F, 0, 0, 0]);
+
+        let window = target.window(0..8, 1..3).unwrap();
+        assert_eq!(window.count_ones(), 10);
+        assert!(window.any());
+        assert!(!window.all());
+
+        let mut mask = Bitmap::new(8, 2).unwrap();
+        mask.set(4, 0, true);
+        let mut window = target.window_mut(0..8, 1..3).unwrap();
+        window &= &mask;
+        assert_eq!(target.count_ones(), 1);
+        assert!(target.get(4, 1));
+    }
+
+    #[test]
+    fn sibling_window_ops() {
+        let mut bitmap = Bitmap::new(16, 2).unwrap();
+        bitmap.set(1, 0, true);
+        bitmap.set(9, 1, true);
+
+        let mut window = bitmap.window_mut(.., ..).unwrap();
+        let (mut left, right) = window.split_horizontal_mut(8).unwrap();
+        left ^= &right;
+        assert_eq!(left.count_ones(), 2);
+        assert_eq!(right.count_ones(), 1);
+        assert_eq!(
+            bitmap.data_ref(),
+            [0b0100_0000, 0, 0b0100_0000, 0b0100_0000]
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use crate::{Bitmap, DataRef, Grid, Origin, Tiles, TILE_SIZE};
+
+/// The pixels of one tile, one byte per row.
+pub type TileBytes = [u8; TILE_SIZE];
+
+/// The pixels of one tile as mutable references into a [Bitmap], one byte per row.
+pub type TileBytesMut<'t> = [&'t mut u8; TILE_SIZE];
+
+impl Bitmap {
+    /// The number of complete tiles in the width direction.
+    #[must_use]
+    pub fn tile_width(&self) -> usize {
+        self.width() / TILE_SIZE
+    }
+
+    /// The number of complete tiles in the height direction.
+    ///
+    /// Rows below the last complete tile are not part of any tile.
+    #[must_use]
+    pub fn tile_height(&self) -> usize {
+        self.height() / TILE_SIZE
+    }
+
+    /// Gets the pixels of the tile at the specified position.
+    ///
+    /// returns: None if the tile is out of bounds
+    #[must_use]
+    pub fn tile(&self, origin: Origin<Tiles>) -> Option<TileBytes> {
+        if origin.x >= self.tile_width() || origin.y >= self.tile_height() {
+            return None;
+        }
+        let stride = self.tile_width();
+        let first = origin.y * TILE_SIZE * stride + origin.x;
+        let data = self.data_ref();
+        Some(std::array::from_fn(|row| data[first + row * stride]))
+    }
+
+    /// Sets the pixels of the tile at the specified position.
+    ///
+    /// returns: false if the tile is out of bounds
+    pub fn set_tile(
+        &mut self,
+        origin: Origin<Tiles>,
+        value: TileBytes,
+    ) -> bool {
+        if origin.x >= self.tile_width() || origin.y >= self.tile_height() {
+            return false;
+        }
+        let stride = self.tile_width();
+        let first = origin.y * TILE_SIZE * stride + origin.x;
+        let data = self.data_ref_mut();
+        for (row, byte) in value.into_iter().enumerate() {
+            data[first + row * stride] = byte;
+        }
+        true
+    }
+
+    /// Iterates over all complete tiles, row by row.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use servicepoint::*;
+    /// let mut bitmap = Bitmap::new(16, 8).unwrap();
+    /// bitmap.set(12, 3, true);
+    /// let lit: Vec<_> = bitmap
+    ///     .tiles()
+    ///     .filter(|(_, tile)| tile.iter().any(|row| *row != 0))
+    ///     .map(|(origin, _)| origin)
+    ///     .collect();
+    /// assert_eq!(lit, [Origin::new(1, 0)]);
+    /// ```
+    pub fn tiles(
+        &self,
+    ) -> impl Iterator<Item = (Origin<Tiles>, TileBytes)> + '_ {
+        let width = self.tile_width();
+        (0..self.tile_height()).flat_map(move |y| {
+            (0..width).map(move |x| {
+                let origin = Origin::new(x, y);
+                #[allow(
+                    clippy::unwrap_used,
+                    reason = "the origin is always in bounds"
+                )]
+                (origin, self.tile(origin).unwrap())
+            })
+        })
+    }
+
+    /// Iterates over all complete tiles, row by row, allowing to change their pixels.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use servicepoint::*;
+    /// let mut bitmap = Bitmap::new(16, 8).unwrap();
+    /// for (origin, rows) in bitmap.tiles_mut() {
+    ///     if origin.x == 1 {
+    ///         *rows[0] = 0xFF;
+    ///     }
+    /// }
+    /// assert_eq!(bitmap.data_ref()[..2], [0x00, 0xFF]);
+    /// ```
+    pub fn tiles_mut(
+        &mut self,
+    ) -> impl Iterator<Item = (Origin<Tiles>, TileBytesMut<'_>)> {
+        let width = self.tile_width();
+        // an empty bitmap has no tiles, but chunks cannot be empty
+        let band_len = (width * TILE_SIZE).max(1);
+        self.data_ref_mut()
+            .chunks_exact_mut(band_len)
+            .enumerate()
+            .flat_map(move |(y, band)| {
+                let mut rows = band.chunks_exact_mut(width.max(1));
+                #[allow(
+                    clippy::unwrap_used,
+                    reason = "a band always contains TILE_SIZE rows"
+                )]
+                let mut rows: [_; TILE_SIZE] =
+                    std::array::from_fn(|_| rows.next().unwrap().iter_mut());
+                (0..width).map(move |x| {
+                    #[allow(
+                        clippy::unwrap_used,
+                        reason = "each row contains one byte per tile"
+                    )]
+                    let bytes =
+                        std::array::from_fn(|row| rows[row].next().unwrap());
+                    (Origin::new(x, y), bytes)
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Bitmap, DataRef, Grid, GridMut, Origin};
+
+    #[test]
+    fn get_and_set() {
+        let mut bitmap = Bitmap::new(16, 20).unwrap();
+        assert_eq!((bitmap.tile_width(), bitmap.tile_height()), (2, 2));
+        assert!(bitmap.tile(Origin::new(2, 0)).is_none());
+        assert!(bitmap.tile(Origin::new(0, 2)).is_none());
+        assert!(!bitmap.set_tile(Origin::new(0, 2), [0xFF; 8]));
+
+        let glyph = [1, 2, 3, 4, 5, 6, 7, 8];
+        assert!(bitmap.set_tile(Origin::new(1, 1), glyph));
+        assert_eq!(bitmap.tile(Origin::new(1, 1)), Some(glyph));
+        assert_eq!(bitmap.tile(Origin::new(0, 1)), Some([0; 8]));
+        assert!(bitmap.get(12, 15));
+        assert!(bitmap.get(15, 14));
+        assert!(!bitmap.get(15, 15));
+    }
+
+    #[test]
+    fn iterate() {
+        let mut bitmap = Bitmap::new(24, 17).unwrap();
+        bitmap.set(9, 10, true);
+
+        let tiles: Vec<_> = bitmap.tiles().collect();
+        assert_eq!(tiles.len(), 6);
+        assert_eq!(tiles[4].0, Origin::new(1, 1));
+        assert_eq!(tiles[4].1, [0, 0, 0b0100_0000, 0, 0, 0, 0, 0]);
+        assert_eq!(
+            tiles.iter().map(|(origin, _)| *origin).collect::<Vec<_>>(),
+            bitmap
+                .tiles_mut()
+                .map(|(origin, _)| origin)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn iterate_mut() {
+        let mut bitmap = Bitmap::new(16, 16).unwrap();
+        for (origin, rows) in bitmap.tiles_mut() {
+            for (index, row) in rows.into_iter().enumerate() {
+                *row = (origin.y * 2 + origin.x) as u8 * 8 + index as u8;
+            }
+        }
+        for (index, tile) in bitmap.tiles().enumerate() {
+            let first = index as u8 * 8;
+            assert_eq!(tile.1, std::array::from_fn(|i| first + i as u8));
+        }
+        assert_eq!(bitmap.data_ref()[..4], [0, 8, 1, 9]);
+    }
+
+    #[test]
+    fn no_tiles() {
+        let mut bitmap = Bitmap::new(0, 0).unwrap();
+        assert_eq!(bitmap.tiles().count(), 0);
+        assert_eq!(bitmap.tiles_mut().count(), 0);
+        let mut bitmap = Bitmap::new(8, 7).unwrap();
+        assert_eq!(bitmap.tiles().count(), 0);
+        assert_eq!(bitmap.tiles_mut().count(), 0);
+    }
+}
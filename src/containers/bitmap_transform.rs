@@ -0,0 +1,473 @@
+use crate::{Bitmap, DataRef, Grid, GridMut, TILE_SIZE};
+
+/// Specifies what happens to pixels that are moved out of a [Bitmap] by [`Bitmap::shift`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShiftMode {
+    /// Pixels moved out on one side re-appear on the other side.
+    Wrap,
+    /// Pixels moved out are discarded, the free space is filled with the provided value.
+    Fill(bool),
+}
+
+impl Bitmap {
+    /// Creates a copy of the [Bitmap] rotated by 90 degrees clockwise.
+    ///
+    /// returns: the rotated [Bitmap], or None if the height is not a multiple of 8.
+    #[must_use]
+    pub fn rotate_90(&self) -> Option<Self> {
+        let mut result = self.transpose()?;
+        result.flip_horizontal();
+        Some(result)
+    }
+
+    /// Creates a copy of the [Bitmap] rotated by 180 degrees.
+    #[must_use]
+    pub fn rotate_180(&self) -> Self {
+        let mut result = self.clone();
+        result.flip_horizontal();
+        result.flip_vertical();
+        result
+    }
+
+    /// Creates a copy of the [Bitmap] rotated by 270 degrees clockwise.
+    ///
+    /// returns: the rotated [Bitmap], or None if the height is not a multiple of 8.
+    #[must_use]
+    pub fn rotate_270(&self) -> Option<Self> {
+        let mut result = self.transpose()?;
+        result.flip_vertical();
+        Some(result)
+    }
+
+    /// Creates a copy of the [Bitmap] rotated clockwise around its center by an arbitrary angle.
+    ///
+    /// The size stays the same, so corners may be cut off.
+    /// Pixels that have no source are off.
+    /// Uses nearest-neighbour resampling.
+    ///
+    /// # Arguments
+    ///
+    /// - `radians`: the angle to rotate by
+    #[must_use]
+    #[allow(
+        clippy::cast_precision_loss,
+        reason = "bitmap sizes are way below the precision limit"
+    )]
+    pub fn rotate(&self, radians: f64) -> Self {
+        let mut result = Self::new_like(self);
+        let (sin, cos) = radians.sin_cos();
+        let center_x = (self.width() as f64 - 1.0) / 2.0;
+        let center_y = (self.height() as f64 - 1.0) / 2.0;
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                // inverse rotation: where does this pixel come from?
+                let dx = x as f64 - center_x;
+                let dy = y as f64 - center_y;
+                let source_x = (cos * dx + sin * dy + center_x).round();
+                let source_y = (cos * dy - sin * dx + center_y).round();
+                if source_x < 0.0 || source_y < 0.0 {
+                    continue;
+                }
+                #[allow(
+                    clippy::cast_possible_truncation,
+                    clippy::cast_sign_loss,
+                    reason = "rounded and checked to be positive, too large values are out of bounds anyway"
+                )]
+                let value =
+                    self.get_optional(source_x as usize, source_y as usize);
+                if value == Some(true) {
+                    result.set(x, y, true);
+                }
+            }
+        }
+        result
+    }
+
+    /// Mirrors the [Bitmap] in place, swapping left and right.
+    pub fn flip_horizontal(&mut self) {
+        let row_bytes = self.width() / TILE_SIZE;
+        if row_bytes == 0 {
+            return;
+        }
+        for row in self.data_ref_mut().chunks_exact_mut(row_bytes) {
+            row.reverse();
+            for byte in row {
+                *byte = byte.reverse_bits();
+            }
+        }
+    }
+
+    /// Mirrors the [Bitmap] in place, swapping top and bottom.
+    pub fn flip_vertical(&mut self) {
+        let row_bytes = self.width() / TILE_SIZE;
+        let height = self.height();
+        let data = self.data_ref_mut();
+        for y in 0..height / 2 {
+            let (top, bottom) = data.split_at_mut((height - 1 - y) * row_bytes);
+            top[y * row_bytes..(y + 1) * row_bytes]
+                .swap_with_slice(&mut bottom[..row_bytes]);
+        }
+    }
+
+    /// Creates a copy of the [Bitmap] with x and y swapped.
+    ///
+    /// returns: the transposed [Bitmap], or None if the height is not a multiple of 8.
+    #[must_use]
+    pub fn transpose(&self) -> Option<Self> {
+        let mut result = Self::new(self.height(), self.width())?;
+        let source_row_bytes = self.width() / TILE_SIZE;
+        let target_row_bytes = result.width() / TILE_SIZE;
+        let source = self.data_ref();
+        let target = result.data_ref_mut();
+
+        // both dimensions are multiples of 8, so this can be done in blocks of 8x8 pixels
+        for block_y in 0..self.height() / TILE_SIZE {
+            for block_x in 0..source_row_bytes {
+                let mut block = [0u8; TILE_SIZE];
+                for (row, byte) in block.iter_mut().enumerate() {
+                    *byte = source[(block_y * TILE_SIZE + row)
+                        * source_row_bytes
+                        + block_x];
+                }
+
+                let transposed = transpose_block(block);
+                for (row, byte) in transposed.into_iter().enumerate() {
+                    target[(block_x * TILE_SIZE + row) * target_row_bytes
+                        + block_y] = byte;
+                }
+            }
+        }
+
+        Some(result)
+    }
+
+    /// Creates a copy of the [Bitmap] where every pixel is repeated `factor` times in both directions.
+    ///
+    /// # Panics
+    ///
+    /// - when `factor` is 0
+    #[must_use]
+    pub fn scale_up(&self, factor: usize) -> Self {
+        assert!(factor > 0);
+        #[allow(
+            clippy::unwrap_used,
+            reason = "a multiple of a multiple of 8 is still a multiple of 8"
+        )]
+        let mut result =
+            Self::new(self.width() * factor, self.height() * factor).unwrap();
+        for y in 0..result.height() {
+            for x in 0..result.width() {
+                if self.get(x / factor, y / factor) {
+                    result.set(x, y, true);
+                }
+            }
+        }
+        result
+    }
+
+    /// Creates a copy of the [Bitmap] scaled to the specified size.
+    ///
+    /// This can be used for fractional scaling in both directions.
+    /// Uses nearest-neighbour resampling.
+    ///
+    /// returns: the scaled [Bitmap], or None if `width` is not a multiple of 8.
+    #[must_use]
+    pub fn scale_to(&self, width: usize, height: usize) -> Option<Self> {
+        let mut result = Self::new(width, height)?;
+        if self.width() == 0 || self.height() == 0 {
+            return Some(result);
+        }
+
+        for y in 0..height {
+            let source_y = y * self.height() / height;
+            for x in 0..width {
+                let source_x = x * self.width() / width;
+                if self.get(source_x, source_y) {
+                    result.set(x, y, true);
+                }
+            }
+        }
+        Some(result)
+    }
+
+    /// Moves all pixels by the specified amount in place.
+    ///
+    /// Positive values move pixels to the right and to the bottom.
+    ///
+    /// Shifting by multiples of 8 in x-direction only moves whole bytes.
+    ///
+    /// # Arguments
+    ///
+    /// - `dx`, `dy`: amount of pixels to move in each direction
+    /// - `mode`: what to do with the pixels moved out of the bitmap
+    pub fn shift(&mut self, dx: isize, dy: isize, mode: ShiftMode) {
+        let width = self.width();
+        let height = self.height();
+        if width == 0 || height == 0 {
+            return;
+        }
+
+        let row_bytes = width / TILE_SIZE;
+        let fill_byte = match mode {
+            ShiftMode::Fill(true) => 0xFF,
+            ShiftMode::Wrap | ShiftMode::Fill(false) => 0x00,
+        };
+
+        if dy != 0 {
+            let data = self.data_ref_mut();
+            let rows = match mode {
+                ShiftMode::Wrap => dy.unsigned_abs() % height,
+                ShiftMode::Fill(_) => dy.unsigned_abs().min(height),
+            };
+            let bytes = rows * row_bytes;
+            match (mode, dy > 0) {
+                (ShiftMode::Wrap, true) => {
+                    data.rotate_right(bytes);
+                }
+                (ShiftMode::Wrap, false) => {
+                    data.rotate_left(bytes);
+                }
+                (ShiftMode::Fill(_), true) => {
+                    data.copy_within(..data.len() - bytes, bytes);
+                    data[..bytes].fill(fill_byte);
+                }
+                (ShiftMode::Fill(_), false) => {
+                    data.copy_within(bytes.., 0);
+                    let len = data.len();
+                    data[len - bytes..].fill(fill_byte);
+                }
+            }
+        }
+
+        if dx == 0 {
+            return;
+        }
+
+        if dx.unsigned_abs() % TILE_SIZE == 0 {
+            let moved = match mode {
+                ShiftMode::Wrap => dx.unsigned_abs() / TILE_SIZE % row_bytes,
+                ShiftMode::Fill(_) => {
+                    (dx.unsigned_abs() / TILE_SIZE).min(row_bytes)
+                }
+            };
+            for row in self.data_ref_mut().chunks_exact_mut(row_bytes) {
+                match (mode, dx > 0) {
+                    (ShiftMode::Wrap, true) => {
+                        row.rotate_right(moved);
+                    }
+                    (ShiftMode::Wrap, false) => {
+                        row.rotate_left(moved);
+                    }
+                    (ShiftMode::Fill(_), true) => {
+                        row.copy_within(..row_bytes - moved, moved);
+                        row[..moved].fill(fill_byte);
+                    }
+                    (ShiftMode::Fill(_), false) => {
+                        row.copy_within(moved.., 0);
+                        row[row_bytes - moved..].fill(fill_byte);
+                    }
+                }
+            }
+            return;
+        }
+
+        let distance = dx.unsigned_abs();
+        let mut row = vec![false; width];
+        for y in 0..height {
+            for (x, value) in row.iter_mut().enumerate() {
+                let source_x = if dx > 0 {
+                    x.checked_sub(distance)
+                } else {
+                    x.checked_add(distance)
+                };
+                *value = match mode {
+                    ShiftMode::Wrap => {
+                        let offset = distance % width;
+                        let source_x = if dx > 0 {
+                            x + width - offset
+                        } else {
+                            x + offset
+                        };
+                        self.get(source_x % width, y)
+                    }
+                    ShiftMode::Fill(fill) => source_x
+                        .and_then(|source_x| self.get_optional(source_x, y))
+                        .unwrap_or(fill),
+                };
+            }
+            for (x, value) in row.iter().enumerate() {
+                self.set(x, y, *value);
+            }
+        }
+    }
+
+    fn new_like(other: &Self) -> Self {
+        #[allow(
+            clippy::unwrap_used,
+            reason = "the other bitmap already has a valid size"
+        )]
+        Self::new(other.width(), other.height()).unwrap()
+    }
+}
+
+/// Transposes an 8x8 block of pixels, where each byte is one row.
+fn transpose_block(block: [u8; TILE_SIZE]) -> [u8; TILE_SIZE] {
+    let mut result = [0u8; TILE_SIZE];
+    for (row, byte) in block.iter().enumerate() {
+        for (column, target) in result.iter_mut().enumerate() {
+            let bit = (byte >> (7 - column)) & 1;
+            *target |= bit << (7 - row);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Bitmap, DataRef, Grid, GridMut, ShiftMode};
+
+    fn l_shape() -> Bitmap {
+        // X.......
+        // X.......
+        // XX......
+        let mut bitmap = Bitmap::new(8, 3).unwrap();
+        bitmap.set(0, 0, true);
+        bitmap.set(0, 1, true);
+        bitmap.set(0, 2, true);
+        bitmap.set(1, 2, true);
+        bitmap
+    }
+
+    #[test]
+    fn flip_horizontal() {
+        let mut bitmap = Bitmap::load(16, 1, &[0x80, 0x03]).unwrap();
+        bitmap.flip_horizontal();
+        assert_eq!(bitmap.data_ref(), [0xC0, 0x01]);
+    }
+
+    #[test]
+    fn flip_vertical() {
+        let mut bitmap = l_shape();
+        bitmap.flip_vertical();
+        assert_eq!(bitmap.data_ref(), [0xC0, 0x80, 0x80]);
+    }
+
+    #[test]
+    fn transpose() {
+        let bitmap =
+            Bitmap::load(8, 8, &[0xFF, 0, 0, 0, 0, 0, 0, 0x01]).unwrap();
+        let transposed = bitmap.transpose().unwrap();
+        assert_eq!(
+            transposed.data_ref(),
+            [0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x81]
+        );
+        assert_eq!(transposed.transpose().unwrap(), bitmap);
+
+        assert_eq!(l_shape().transpose(), None);
+    }
+
+    #[test]
+    fn transpose_non_square() {
+        let mut bitmap = Bitmap::new(16, 8).unwrap();
+        bitmap.set(12, 3, true);
+        let transposed = bitmap.transpose().unwrap();
+        assert_eq!(transposed.width(), 8);
+        assert_eq!(transposed.height(), 16);
+        assert!(transposed.get(3, 12));
+        assert_eq!(transposed.iter().filter(|p| **p).count(), 1);
+    }
+
+    #[test]
+    fn rotate_right_angles() {
+        let mut bitmap = Bitmap::new(16, 8).unwrap();
+        bitmap.set(0, 0, true);
+
+        let rotated = bitmap.rotate_90().unwrap();
+        assert!(rotated.get(7, 0));
+        let rotated = bitmap.rotate_180();
+        assert!(rotated.get(15, 7));
+        let rotated = bitmap.rotate_270().unwrap();
+        assert!(rotated.get(0, 15));
+
+        assert_eq!(
+            bitmap
+                .rotate_90()
+                .unwrap()
+                .rotate_90()
+                .unwrap()
+                .rotate_180(),
+            bitmap
+        );
+        assert_eq!(l_shape().rotate_90(), None);
+    }
+
+    #[test]
+    fn rotate_arbitrary() {
+        let bitmap = Bitmap::load(8, 8, &[0, 0, 0, 0xFF, 0, 0, 0, 0]).unwrap();
+        assert_eq!(bitmap.rotate(0.0), bitmap);
+
+        let rotated = bitmap.rotate(std::f64::consts::FRAC_PI_2);
+        assert_eq!(rotated.iter().filter(|p| **p).count(), 8);
+        for y in 0..8 {
+            assert_eq!(
+                rotated.get_row(y).unwrap().iter().filter(|p| **p).count(),
+                1
+            );
+        }
+    }
+
+    #[test]
+    fn scale_up() {
+        let bitmap = Bitmap::load(8, 1, &[0x81]).unwrap();
+        let scaled = bitmap.scale_up(2);
+        assert_eq!(scaled.width(), 16);
+        assert_eq!(scaled.height(), 2);
+        assert_eq!(scaled.data_ref(), [0xC0, 0x03, 0xC0, 0x03]);
+    }
+
+    #[test]
+    fn scale_to() {
+        let bitmap = Bitmap::load(16, 2, &[0xC0, 0x03, 0xC0, 0x03]).unwrap();
+        let scaled = bitmap.scale_to(8, 1).unwrap();
+        assert_eq!(scaled.data_ref(), [0x81]);
+
+        let scaled = bitmap.scale_to(24, 3).unwrap();
+        assert!(scaled.get(0, 2));
+        assert!(scaled.get(23, 2));
+        assert!(!scaled.get(12, 1));
+
+        assert_eq!(bitmap.scale_to(7, 1), None);
+    }
+
+    #[test]
+    fn shift_wrap() {
+        let mut bitmap = l_shape();
+        bitmap.shift(-1, 1, ShiftMode::Wrap);
+        assert_eq!(bitmap.data_ref(), [0x81, 0x01, 0x01]);
+        bitmap.shift(9, 5, ShiftMode::Wrap);
+        assert_eq!(bitmap.data_ref(), [0x80, 0x80, 0xC0]);
+    }
+
+    #[test]
+    fn shift_fill() {
+        let mut bitmap = l_shape();
+        bitmap.shift(2, -1, ShiftMode::Fill(false));
+        assert_eq!(bitmap.data_ref(), [0x20, 0x30, 0x00]);
+
+        let mut bitmap = l_shape();
+        bitmap.shift(0, 5, ShiftMode::Fill(true));
+        assert_eq!(bitmap.data_ref(), [0xFF, 0xFF, 0xFF]);
+    }
+
+    #[test]
+    fn shift_bytes() {
+        let mut bitmap = Bitmap::load(24, 1, &[0x01, 0x02, 0x03]).unwrap();
+        bitmap.shift(8, 0, ShiftMode::Wrap);
+        assert_eq!(bitmap.data_ref(), [0x03, 0x01, 0x02]);
+        bitmap.shift(-32, 0, ShiftMode::Wrap);
+        assert_eq!(bitmap.data_ref(), [0x01, 0x02, 0x03]);
+        bitmap.shift(8, 0, ShiftMode::Wrap);
+        bitmap.shift(-16, 0, ShiftMode::Fill(true));
+        assert_eq!(bitmap.data_ref(), [0x02, 0xFF, 0xFF]);
+    }
+}
@@ -0,0 +1,444 @@
+use crate::{BinaryOperation, Bitmap, DataRef, Grid, GridMut, TILE_SIZE};
+use std::ops::Range;
+
+/// The part of a source grid that is visible when placed on a target grid at an offset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ClippedArea {
+    /// columns of the source that end up in the target
+    pub(crate) source_xs: Range<usize>,
+    /// rows of the source that end up in the target
+    pub(crate) source_ys: Range<usize>,
+    /// target column of the first visible source column
+    pub(crate) target_x: usize,
+    /// target row of the first visible source row
+    pub(crate) target_y: usize,
+}
+
+impl ClippedArea {
+    /// Calculates where `source` lands when placed on `target` at position (`x`, `y`).
+    ///
+    /// returns: None if nothing would be visible
+    pub(crate) fn new<T, U>(
+        target: &(impl Grid<T> + ?Sized),
+        source: &(impl Grid<U> + ?Sized),
+        x: isize,
+        y: isize,
+    ) -> Option<Self> {
+        let columns = clip_axis(target.width(), source.width(), x)?;
+        let rows = clip_axis(target.height(), source.height(), y)?;
+        Some(Self {
+            source_xs: columns.0,
+            source_ys: rows.0,
+            target_x: columns.1,
+            target_y: rows.1,
+        })
+    }
+
+    /// Iterates over all visible positions as `(source_x, source_y, target_x, target_y)`.
+    pub(crate) fn positions(
+        &self,
+    ) -> impl Iterator<Item = (usize, usize, usize, usize)> + '_ {
+        self.source_ys.clone().flat_map(move |source_y| {
+            let target_y = self.target_y + source_y - self.source_ys.start;
+            self.source_xs.clone().map(move |source_x| {
+                let target_x = self.target_x + source_x - self.source_xs.start;
+                (source_x, source_y, target_x, target_y)
+            })
+        })
+    }
+}
+
+fn clip_axis(
+    target_len: usize,
+    source_len: usize,
+    offset: isize,
+) -> Option<(Range<usize>, usize)> {
+    let distance = offset.unsigned_abs();
+    let (skip, target_start) = if offset < 0 {
+        (distance, 0)
+    } else {
+        (0, distance)
+    };
+    if skip >= source_len || target_start >= target_len {
+        return None;
+    }
+    let len = (source_len - skip).min(target_len - target_start);
+    Some((skip..skip + len, target_start))
+}
+
+impl BinaryOperation {
+    /// Combines one pixel of the target (`old`) with one pixel of the source (`new`).
+    #[must_use]
+    pub fn apply(self, old: bool, new: bool) -> bool {
+        match self {
+            BinaryOperation::Overwrite => new,
+            BinaryOperation::And => old && new,
+            BinaryOperation::Or => old || new,
+            BinaryOperation::Xor => old != new,
+        }
+    }
+
+    /// Combines eight pixels of the target (`old`) with eight pixels of the source (`new`).
+    #[must_use]
+    pub fn apply_byte(self, old: u8, new: u8) -> u8 {
+        match self {
+            BinaryOperation::Overwrite => new,
+            BinaryOperation::And => old & new,
+            BinaryOperation::Or => old | new,
+            BinaryOperation::Xor => old ^ new,
+        }
+    }
+}
+
+/// Extension methods for any [`GridMut<bool>`].
+pub trait BoolGridMutExt {
+    /// Combines the provided grid with this grid at the specified position.
+    ///
+    /// The position can be negative or out of bounds, only overlapping cells are changed.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: the grid to draw
+    /// - `x` and `y`: position of the top left corner of `source` in this grid
+    /// - `operation`: how to combine the existing cells with the ones from `source`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use servicepoint::*;
+    /// let mut target = Bitmap::new(16, 16).unwrap();
+    /// let mut sprite = Bitmap::new(8, 8).unwrap();
+    /// sprite.fill(true);
+    /// let sprite = sprite.window(0..8, 0..8).unwrap();
+    /// target.blit_with(&sprite, -4, 12, BinaryOperation::Xor);
+    /// ```
+    fn blit_with(
+        &mut self,
+        source: &impl Grid<bool>,
+        x: isize,
+        y: isize,
+        operation: BinaryOperation,
+    );
+
+    /// Copies the cells of the provided grid where the mask is set.
+    ///
+    /// Cells where the mask is not set stay as they are, which makes them transparent.
+    /// The position can be negative or out of bounds, only overlapping cells are changed.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: the grid to draw
+    /// - `mask`: which cells of `source` to draw, has to be at least as big as `source`
+    /// - `x` and `y`: position of the top left corner of `source` in this grid
+    ///
+    /// # Panics
+    ///
+    /// - when the mask is smaller than the source
+    fn blit_masked(
+        &mut self,
+        source: &impl Grid<bool>,
+        mask: &impl Grid<bool>,
+        x: isize,
+        y: isize,
+    );
+}
+
+impl<G: GridMut<bool>> BoolGridMutExt for G {
+    fn blit_with(
+        &mut self,
+        source: &impl Grid<bool>,
+        x: isize,
+        y: isize,
+        operation: BinaryOperation,
+    ) {
+        let Some(area) = ClippedArea::new(self, source, x, y) else {
+            return;
+        };
+        for (source_x, source_y, target_x, target_y) in area.positions() {
+            let old = self.get(target_x, target_y);
+            let new = source.get(source_x, source_y);
+            self.set(target_x, target_y, operation.apply(old, new));
+        }
+    }
+
+    fn blit_masked(
+        &mut self,
+        source: &impl Grid<bool>,
+        mask: &impl Grid<bool>,
+        x: isize,
+        y: isize,
+    ) {
+        assert_mask_size(source, mask);
+        let Some(area) = ClippedArea::new(self, source, x, y) else {
+            return;
+        };
+        for (source_x, source_y, target_x, target_y) in area.positions() {
+            if mask.get(source_x, source_y) {
+                self.set(target_x, target_y, source.get(source_x, source_y));
+            }
+        }
+    }
+}
+
+fn assert_mask_size(source: &impl Grid<bool>, mask: &impl Grid<bool>) {
+    assert!(
+        mask.width() >= source.width() && mask.height() >= source.height(),
+        "mask of size {}x{} is too small for source of size {}x{}",
+        mask.width(),
+        mask.height(),
+        source.width(),
+        source.height()
+    );
+}
+
+impl Bitmap {
+    /// Like [`BoolGridMutExt::blit_with`], but faster because both sides are [Bitmap]s.
+    ///
+    /// When `x` is a multiple of 8, whole bytes are combined.
+    /// Otherwise, the rows are combined as bit slices.
+    pub fn blit_bitmap(
+        &mut self,
+        source: &Bitmap,
+        x: isize,
+        y: isize,
+        operation: BinaryOperation,
+    ) {
+        let Some(area) = ClippedArea::new(self, source, x, y) else {
+            return;
+        };
+
+        if let Some(aligned) = ByteAlignedArea::new(self, source, &area) {
+            let source_data = source.data_ref();
+            let target_data = self.data_ref_mut();
+            for (source_row, target_row) in aligned.rows() {
+                let source_row = &source_data[source_row];
+                let target_row = &mut target_data[target_row];
+                for (old, new) in target_row.iter_mut().zip(source_row) {
+                    *old = operation.apply_byte(*old, *new);
+                }
+            }
+            return;
+        }
+
+        let target_xs = area.target_x..area.target_x + area.source_xs.len();
+        for (source_y, target_y) in area.source_ys.clone().zip(area.target_y..)
+        {
+            let new = source.row_bits(source_y, area.source_xs.clone());
+            let old = self.row_bits_mut(target_y, target_xs.clone());
+            match operation {
+                BinaryOperation::Overwrite => old.copy_from_bitslice(new),
+                BinaryOperation::And => *old &= new,
+                BinaryOperation::Or => *old |= new,
+                BinaryOperation::Xor => *old ^= new,
+            }
+        }
+    }
+
+    /// Like [`BoolGridMutExt::blit_masked`], but faster because all sides are [Bitmap]s.
+    ///
+    /// When `x` is a multiple of 8, whole bytes are combined.
+    ///
+    /// # Panics
+    ///
+    /// - when the mask is smaller than the source
+    pub fn blit_bitmap_masked(
+        &mut self,
+        source: &Bitmap,
+        mask: &Bitmap,
+        x: isize,
+        y: isize,
+    ) {
+        assert_mask_size(source, mask);
+        let Some(area) = ClippedArea::new(self, source, x, y) else {
+            return;
+        };
+
+        let Some(aligned) = ByteAlignedArea::new(self, source, &area) else {
+            self.blit_masked(source, mask, x, y);
+            return;
+        };
+
+        let mask_row_bytes = mask.width() / TILE_SIZE;
+        let source_row_bytes = source.width() / TILE_SIZE;
+        let source_data = source.data_ref();
+        let mask_data = mask.data_ref();
+        let target_data = self.data_ref_mut();
+        for (source_row, target_row) in aligned.rows() {
+            // the mask may be wider than the source, so the index needs to be translated
+            let y = source_row.start / source_row_bytes;
+            let column = source_row.start % source_row_bytes;
+            let mask_start = y * mask_row_bytes + column;
+            let mask_row =
+                &mask_data[mask_start..mask_start + source_row.len()];
+            let source_row = &source_data[source_row];
+            let target_row = &mut target_data[target_row];
+            for ((old, new), mask) in
+                target_row.iter_mut().zip(source_row).zip(mask_row)
+            {
+                *old = (*old & !mask) | (new & mask);
+            }
+        }
+    }
+}
+
+/// A [`ClippedArea`] between two [Bitmap]s that starts and ends on byte boundaries.
+struct ByteAlignedArea {
+    source_row_bytes: usize,
+    target_row_bytes: usize,
+    source_column: usize,
+    target_column: usize,
+    len: usize,
+    source_ys: Range<usize>,
+    target_y: usize,
+}
+
+impl ByteAlignedArea {
+    fn new(
+        target: &Bitmap,
+        source: &Bitmap,
+        area: &ClippedArea,
+    ) -> Option<Self> {
+        if area.target_x % TILE_SIZE != 0
+            || area.source_xs.start % TILE_SIZE != 0
+            || area.source_xs.len() % TILE_SIZE != 0
+        {
+            return None;
+        }
+        Some(Self {
+            source_row_bytes: source.width() / TILE_SIZE,
+            target_row_bytes: target.width() / TILE_SIZE,
+            source_column: area.source_xs.start / TILE_SIZE,
+            target_column: area.target_x / TILE_SIZE,
+            len: area.source_xs.len() / TILE_SIZE,
+            source_ys: area.source_ys.clone(),
+            target_y: area.target_y,
+        })
+    }
+
+    /// The byte ranges of each row in the source and the target data.
+    fn rows(&self) -> impl Iterator<Item = (Range<usize>, Range<usize>)> + '_ {
+        self.source_ys.clone().zip(self.target_y..).map(
+            move |(source_y, target_y)| {
+                let source_start =
+                    source_y * self.source_row_bytes + self.source_column;
+                let target_start =
+                    target_y * self.target_row_bytes + self.target_column;
+                (
+                    source_start..source_start + self.len,
+                    target_start..target_start + self.len,
+                )
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ClippedArea;
+    use crate::{
+        BinaryOperation, Bitmap, BoolGridMutExt, ByteGrid, DataRef, GridMut,
+    };
+
+    #[test]
+    fn clip() {
+        let target = ByteGrid::new(4, 3);
+        let source = ByteGrid::new(2, 2);
+
+        assert_eq!(
+            ClippedArea::new(&target, &source, 1, 1),
+            Some(ClippedArea {
+                source_xs: 0..2,
+                source_ys: 0..2,
+                target_x: 1,
+                target_y: 1
+            })
+        );
+        assert_eq!(
+            ClippedArea::new(&target, &source, -1, 2),
+            Some(ClippedArea {
+                source_xs: 1..2,
+                source_ys: 0..1,
+                target_x: 0,
+                target_y: 2
+            })
+        );
+        assert_eq!(ClippedArea::new(&target, &source, 4, 0), None);
+        assert_eq!(ClippedArea::new(&target, &source, 0, -2), None);
+    }
+
+    #[test]
+    fn blit_value_grid() {
+        let mut target = ByteGrid::new(3, 3);
+        let source = ByteGrid::load(2, 2, &[1, 2, 3, 4]).unwrap();
+
+        target.blit(&source, -1, 2);
+        target.blit(&source, 2, -1);
+        target.blit(&source, 5, 5);
+        assert_eq!(target.data_ref(), [0, 0, 3, 0, 0, 0, 2, 0, 0]);
+    }
+
+    #[test]
+    fn blit_with_operation() {
+        let mut target = ByteGrid::new(2, 1).map(|_| true);
+        let source = ByteGrid::new(1, 1).map(|_| true);
+
+        target.blit_with(&source, 0, 0, BinaryOperation::Xor);
+        target.blit_with(&source.map(|_| false), 1, 0, BinaryOperation::And);
+        assert_eq!(target.data_ref(), [false, false]);
+        target.blit_with(&source, 1, 0, BinaryOperation::Or);
+        assert_eq!(target.data_ref(), [false, true]);
+    }
+
+    #[test]
+    fn blit_masked() {
+        let mut target = ByteGrid::new(2, 2).map(|_| false);
+        let source = ByteGrid::new(2, 2).map(|_| true);
+        let mask = ByteGrid::load(3, 2, &[1, 0, 0, 0, 1, 0]).unwrap();
+        let mask = mask.map(|v| v == 1);
+
+        target.blit_masked(&source, &mask, 0, 0);
+        assert_eq!(target.data_ref(), [true, false, false, true]);
+    }
+
+    #[test]
+    fn bitmap_fast_paths_match() {
+        let mut source = Bitmap::new(16, 4).unwrap();
+        for (index, mut pixel) in source.iter_mut().enumerate() {
+            pixel.set(index % 3 == 0);
+        }
+        let mut mask = Bitmap::new(24, 4).unwrap();
+        for (index, mut pixel) in mask.iter_mut().enumerate() {
+            pixel.set(index % 5 < 2);
+        }
+
+        for operation in [
+            BinaryOperation::Overwrite,
+            BinaryOperation::And,
+            BinaryOperation::Or,
+            BinaryOperation::Xor,
+        ] {
+            for (x, y) in [(0, 0), (8, 1), (-8, -1), (3, 2), (-5, 0), (20, 3)] {
+                let mut fast = Bitmap::new(24, 5).unwrap();
+                fast.window_mut(4..20, 1..4).unwrap().fill(true);
+                let mut slow = fast.clone();
+
+                fast.blit_bitmap(&source, x, y, operation);
+                slow.blit_with(&source, x, y, operation);
+                assert_eq!(fast, slow, "{operation:?} at ({x}, {y})");
+
+                fast.blit_bitmap_masked(&source, &mask, x, y);
+                slow.blit_masked(&source, &mask, x, y);
+                assert_eq!(fast, slow, "masked at ({x}, {y})");
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn mask_too_small() {
+        let mut target = Bitmap::new(8, 8).unwrap();
+        let source = Bitmap::new(8, 8).unwrap();
+        let mask = Bitmap::new(8, 7).unwrap();
+        target.blit_bitmap_masked(&source, &mask, 0, 0);
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{CharGridMutExt, GridMut, ValueGrid};
+use crate::{
+    containers::text_cells::{glyphs, row_cells, WIDE_CHAR_PADDING},
+    GridMut, ValueGrid,
+};
 
 /// A grid containing UTF-8 characters.
 ///
@@ -22,7 +25,10 @@ pub type CharGrid = ValueGrid<char>;
 impl CharGrid {
     /// Loads a [`CharGrid`] with the specified width from the provided text, wrapping to as many rows as needed.
     ///
-    /// The passed rows are extended with '\0' if needed.
+    /// The passed rows are extended with ' ' if needed.
+    /// Each grapheme cluster takes up one cell, wide characters like CJK ideographs take up two
+    /// and are never split between rows.
+    /// Words are cut at the width boundary, use [`crate::TextLayout`] for word-aware wrapping.
     ///
     /// returns: [`CharGrid`] that contains a copy of the provided data.
     ///
@@ -34,26 +40,31 @@ impl CharGrid {
     /// ```
     #[must_use]
     pub fn wrap_str(width: usize, text: &str) -> Self {
-        let lines = text
-            .split('\n')
-            .flat_map(move |x| {
-                x.chars()
-                    .collect::<Vec<char>>()
-                    .chunks(width)
-                    .map(|c| {
-                        let mut s = String::from_iter(c);
-                        s.push_str(&" ".repeat(width - s.chars().count()));
-                        s
-                    })
-                    .collect::<Vec<String>>()
-            })
-            .collect::<Vec<String>>();
-        let height = lines.len();
-        let mut result = Self::new(width, height);
-        for (row, text_line) in lines.iter().enumerate() {
-            #[allow(clippy::unwrap_used)]
-            // we calculated the width before setting
-            result.set_row_str(row, text_line).unwrap();
+        let mut rows = Vec::new();
+        for line in text.split('\n') {
+            let mut row = Vec::with_capacity(width);
+            for glyph in glyphs(line) {
+                // characters wider than the grid are cut
+                let glyph_width = glyph.width.min(width);
+                if row.len() + glyph_width > width {
+                    rows.push(std::mem::take(&mut row));
+                }
+                row.push(glyph.char);
+                if glyph_width > 1 {
+                    row.push(WIDE_CHAR_PADDING);
+                }
+            }
+            if !row.is_empty() {
+                rows.push(row);
+            }
+        }
+
+        let mut result = Self::new(width, rows.len());
+        result.fill(' ');
+        for (y, row) in rows.into_iter().enumerate() {
+            for (x, char) in row.into_iter().enumerate() {
+                result.set(x, y, char);
+            }
         }
         result
     }
@@ -63,17 +74,15 @@ impl From<&str> for CharGrid {
     fn from(value: &str) -> Self {
         let value = value.replace("\r\n", "\n");
         let mut lines = value.split('\n').collect::<Vec<_>>();
-        let width = lines
-            .iter()
-            .fold(0, move |a, x| std::cmp::max(a, x.chars().count()));
-
         while lines.last().is_some_and(move |line| line.is_empty()) {
             _ = lines.pop();
         }
 
-        let mut grid = Self::new(width, lines.len());
-        for (y, line) in lines.iter().enumerate() {
-            for (x, char) in line.chars().enumerate() {
+        let rows = lines.into_iter().map(row_cells).collect::<Vec<_>>();
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let mut grid = Self::new(width, rows.len());
+        for (y, row) in rows.into_iter().enumerate() {
+            for (x, char) in row.into_iter().enumerate() {
                 grid.set(x, y, char);
             }
         }
@@ -187,4 +196,17 @@ mod test {
         assert_eq!(2, grid.width());
         assert_eq!("ab\nc \nde\nf ", String::from(grid));
     }
+
+    #[test]
+    fn wrap_str_wide_chars() {
+        let grid = CharGrid::wrap_str(3, "a中文\nu\u{308}");
+        assert_eq!("a中 \n文  \nü  ", String::from(grid));
+    }
+
+    #[test]
+    fn str_to_char_grid_graphemes() {
+        let grid = CharGrid::from("中\na\u{308}b");
+        assert_eq!(grid.width(), 2);
+        assert_eq!("中 \näb", String::from(grid));
+    }
 }
@@ -1,4 +1,7 @@
-use crate::{Grid, GridMut, SetValueSeriesError};
+use crate::{
+    containers::text_cells::{glyphs, row_cells},
+    Grid, GridMut, SetValueSeriesError,
+};
 
 /// Extension methods for any [`Grid<char>`]
 pub trait CharGridExt {
@@ -35,6 +38,9 @@ pub trait CharGridExt {
 pub trait CharGridMutExt {
     /// Overwrites a row in the grid with a str.
     ///
+    /// Each grapheme cluster takes up one cell, wide characters like CJK ideographs take up two.
+    /// Combining sequences are stored as precomposed characters where possible.
+    ///
     /// Returns [`SetValueSeriesError`] if y is out of bounds or `row` is not of the correct size.
     ///
     /// # Examples
@@ -50,7 +56,7 @@ pub trait CharGridMutExt {
         value: &str,
     ) -> Result<(), SetValueSeriesError>;
 
-    /// Overwrites a column in the grid with a str.
+    /// Overwrites a column in the grid with a str, one grapheme cluster per cell.
     ///
     /// Returns [`SetValueSeriesError`] if y is out of bounds or `row` is not of the correct size.
     ///
@@ -86,7 +92,8 @@ impl<G: GridMut<char>> CharGridMutExt for G {
     ) -> Result<(), SetValueSeriesError> {
         let width = self.width();
 
-        let len = value.len();
+        let cells = row_cells(value);
+        let len = cells.len();
         if len > width {
             return Err(SetValueSeriesError::InvalidLength {
                 actual: len,
@@ -102,8 +109,7 @@ impl<G: GridMut<char>> CharGridMutExt for G {
             });
         }
 
-        let chars = value.chars().take(width);
-        for (x, c) in chars.enumerate() {
+        for (x, c) in cells.into_iter().enumerate() {
             self.set(x, y, c);
         }
 
@@ -117,7 +123,8 @@ impl<G: GridMut<char>> CharGridMutExt for G {
     ) -> Result<(), SetValueSeriesError> {
         let height = self.height();
 
-        let len = value.len();
+        let glyphs = glyphs(value);
+        let len = glyphs.len();
         if len > height {
             return Err(SetValueSeriesError::InvalidLength {
                 actual: len,
@@ -133,9 +140,8 @@ impl<G: GridMut<char>> CharGridMutExt for G {
             });
         }
 
-        let chars = value.chars().take(height);
-        for (y, c) in chars.enumerate() {
-            self.set(x, y, c);
+        for (y, glyph) in glyphs.into_iter().enumerate() {
+            self.set(x, y, glyph.char);
         }
 
         Ok(())
@@ -170,4 +176,23 @@ mod test {
         assert_eq!(grid.set_row_str(1, "ab"), Ok(()));
         assert_eq!(grid.get_row_str(1), Some(String::from("ab")));
     }
+
+    #[test]
+    fn multibyte_str() {
+        let mut grid = CharGrid::new(3, 3);
+        assert_eq!(grid.set_row_str(0, "äöü"), Ok(()));
+        assert_eq!(grid.get_row_str(0), Some(String::from("äöü")));
+        assert_eq!(grid.set_row_str(1, "a\u{308}中"), Ok(()));
+        assert_eq!(grid.get_row_str(1), Some(String::from("ä中 ")));
+        assert_eq!(
+            grid.set_row_str(2, "中中"),
+            Err(SetValueSeriesError::InvalidLength {
+                expected: 3,
+                actual: 4
+            })
+        );
+
+        assert_eq!(grid.set_col_str(2, "o\u{308}中x"), Ok(()));
+        assert_eq!(grid.get_col_str(2), Some(String::from("ö中x")));
+    }
 }
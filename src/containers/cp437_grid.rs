@@ -12,9 +12,9 @@ pub type Cp437Grid = ValueGrid<u8>;
 )]
 pub struct InvalidCharError {
     /// invalid character is at this position in input
-    index: usize,
+    pub index: usize,
     /// the invalid character
-    char: char,
+    pub char: char,
 }
 
 impl Cp437Grid {
@@ -28,38 +28,39 @@ impl Cp437Grid {
         value: &str,
         width: usize,
         wrap: bool,
+    ) -> Result<Self, InvalidCharError> {
+        #[allow(
+            clippy::cast_possible_truncation,
+            reason = "ASCII characters fit into u8"
+        )]
+        Self::load_with(value, width, wrap, |char| {
+            char.is_ascii().then_some(char as u8)
+        })
+    }
+
+    /// Loads a [&str] into a [`Cp437Grid`] of specified width, converting each character with `convert`.
+    ///
+    /// '\n' starts a new row. When `wrap` is set, rows that are too long continue in the next row,
+    /// otherwise the rest of the row is dropped.
+    ///
+    /// # Panics
+    ///
+    /// - for width == 0
+    /// - on empty strings
+    pub(crate) fn load_with(
+        value: &str,
+        width: usize,
+        wrap: bool,
+        convert: impl Fn(char) -> Option<u8>,
     ) -> Result<Self, InvalidCharError> {
         assert!(width > 0);
         assert!(!value.is_empty());
 
-        let mut chars = {
-            let mut x = 0;
-            let mut y = 0;
-
-            for (index, char) in value.chars().enumerate() {
-                if !char.is_ascii() {
-                    return Err(InvalidCharError { index, char });
-                }
-
-                let is_lf = char == '\n';
-                if is_lf || (wrap && x == width) {
-                    y += 1;
-                    x = 0;
-                    if is_lf {
-                        continue;
-                    }
-                }
-
-                x += 1;
-            }
-
-            Cp437Grid::new(width, y + 1)
-        };
-
+        let mut cells = Vec::new();
         let mut x = 0;
         let mut y = 0;
-        for char in value.chars().map(move |c| c as u8) {
-            let is_lf = char == b'\n';
+        for (index, char) in value.chars().enumerate() {
+            let is_lf = char == '\n';
             if is_lf || (wrap && x == width) {
                 y += 1;
                 x = 0;
@@ -68,12 +69,18 @@ impl Cp437Grid {
                 }
             }
 
-            if wrap || x < width {
-                chars.set(x, y, char);
+            let cp437 =
+                convert(char).ok_or(InvalidCharError { index, char })?;
+            if x < width {
+                cells.push((x, y, cp437));
             }
             x += 1;
         }
 
+        let mut chars = Cp437Grid::new(width, y + 1);
+        for (x, y, cp437) in cells {
+            chars.set(x, y, cp437);
+        }
         Ok(chars)
     }
 }
@@ -0,0 +1,270 @@
+use crate::{
+    containers::absolute_bounds_to_abs_range, Bitmap, BitmapCommand,
+    CompressionCode, Grid, GridDiff, GridMut, Pixels, Region, Tiles, Window,
+    WindowMut, TILE_SIZE,
+};
+use std::ops::RangeBounds;
+
+/// A [Bitmap] that keeps track of the tiles that changed since the last update was sent.
+///
+/// All writes through [`GridMut`] are tracked, including writes through windows and blits.
+/// Writing a value a pixel already has does not mark it as changed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use servicepoint::*;
+/// # let connection = FakeConnection;
+/// let mut bitmap = DirtyBitmap::from(Bitmap::max_sized());
+/// // initially, the whole bitmap has to be sent
+/// for command in bitmap.take_commands(CompressionCode::default()) {
+///     connection.send_command(command).unwrap();
+/// }
+///
+/// bitmap.set(10, 10, true);
+/// bitmap.window_mut(16..24, 0..8).unwrap().fill(true);
+/// // only the changed tiles are sent, even though they touch diagonally
+/// assert_eq!(
+///     bitmap.take_dirty(),
+///     [
+///         Region::new(Origin::new(16, 0), 8, 8),
+///         Region::new(Origin::new(8, 8), 8, 8),
+///     ]
+/// );
+/// assert!(bitmap.take_dirty().is_empty());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirtyBitmap {
+    bitmap: Bitmap,
+    dirty: GridDiff<Tiles>,
+}
+
+impl DirtyBitmap {
+    /// Wraps the provided bitmap. All of it is considered changed.
+    #[must_use]
+    pub fn new(bitmap: Bitmap) -> Self {
+        let width = (bitmap.width() + TILE_SIZE - 1) / TILE_SIZE;
+        let height = (bitmap.height() + TILE_SIZE - 1) / TILE_SIZE;
+        let mut dirty = GridDiff::unchanged(width, height);
+        dirty.mark_all();
+        Self { bitmap, dirty }
+    }
+
+    /// The current pixels.
+    #[must_use]
+    pub fn bitmap(&self) -> &Bitmap {
+        &self.bitmap
+    }
+
+    /// Unwraps the bitmap, discarding the tracked changes.
+    #[must_use]
+    pub fn into_inner(self) -> Bitmap {
+        self.bitmap
+    }
+
+    /// Checks whether any tile changed since the last call to [`Self::take_dirty`].
+    #[must_use]
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    /// Marks the whole bitmap as changed, e.g. after the display has been reset.
+    pub fn mark_all_dirty(&mut self) {
+        self.dirty.mark_all();
+    }
+
+    /// Returns the parts of the bitmap that changed and marks them as unchanged.
+    ///
+    /// Regions start and end on tile boundaries (or the bottom edge of the bitmap),
+    /// so each of them can be sent as a [`BitmapCommand`].
+    /// They only cover changed tiles, see [`GridDiff::exact_regions`].
+    #[must_use]
+    pub fn take_dirty(&mut self) -> Vec<Region<Pixels>> {
+        let regions = self
+            .dirty
+            .exact_pixel_regions(self.bitmap.width(), self.bitmap.height());
+        self.dirty =
+            GridDiff::unchanged(self.dirty.width(), self.dirty.height());
+        regions
+    }
+
+    /// Like [`Self::take_dirty`], but creates the commands for sending the changed parts.
+    #[must_use]
+    pub fn take_commands(
+        &mut self,
+        compression: CompressionCode,
+    ) -> Vec<BitmapCommand> {
+        self.take_dirty()
+            .into_iter()
+            .map(|region| BitmapCommand {
+                #[allow(
+                    clippy::unwrap_used,
+                    reason = "regions are tile aligned and inside of the bitmap"
+                )]
+                bitmap: self.bitmap.extract(region.xs(), region.ys()).unwrap(),
+                origin: region.origin,
+                compression,
+            })
+            .collect()
+    }
+
+    /// Creates a window into the bitmap.
+    ///
+    /// Returns None in case the window does not fit.
+    #[must_use]
+    pub fn window(
+        &self,
+        xs: impl RangeBounds<usize>,
+        ys: impl RangeBounds<usize>,
+    ) -> Option<Window<'_, bool, Self>> {
+        let xs = absolute_bounds_to_abs_range(xs, self.width())?;
+        let ys = absolute_bounds_to_abs_range(ys, self.height())?;
+        Window::new(self, xs, ys)
+    }
+
+    /// Creates a mutable window into the bitmap. Writes through the window are tracked.
+    ///
+    /// Returns None in case the window does not fit.
+    pub fn window_mut(
+        &mut self,
+        xs: impl RangeBounds<usize>,
+        ys: impl RangeBounds<usize>,
+    ) -> Option<WindowMut<'_, bool, Self>> {
+        let xs = absolute_bounds_to_abs_range(xs, self.width())?;
+        let ys = absolute_bounds_to_abs_range(ys, self.height())?;
+        WindowMut::new(self, xs, ys)
+    }
+}
+
+impl From<Bitmap> for DirtyBitmap {
+    fn from(value: Bitmap) -> Self {
+        Self::new(value)
+    }
+}
+
+impl Grid<bool> for DirtyBitmap {
+    fn get_optional(&self, x: usize, y: usize) -> Option<bool> {
+        self.bitmap.get_optional(x, y)
+    }
+
+    fn width(&self) -> usize {
+        self.bitmap.width()
+    }
+
+    fn height(&self) -> usize {
+        self.bitmap.height()
+    }
+}
+
+impl GridMut<bool> for DirtyBitmap {
+    fn set_optional(&mut self, x: usize, y: usize, value: bool) -> bool {
+        match self.bitmap.get_optional(x, y) {
+            None => false,
+            Some(old) => {
+                if old != value {
+                    self.bitmap.set(x, y, value);
+                    self.dirty.mark(x / TILE_SIZE, y / TILE_SIZE);
+                }
+                true
+            }
+        }
+    }
+
+    fn fill(&mut self, value: bool) {
+        let unchanged = if value {
+            self.bitmap.all()
+        } else {
+            !self.bitmap.any()
+        };
+        if !unchanged {
+            self.bitmap.fill(value);
+            self.dirty.mark_all();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Bitmap, CompressionCode, DataRef, DirtyBitmap, GridMut, Origin, Region,
+    };
+
+    fn clean(width: usize, height: usize) -> DirtyBitmap {
+        let mut bitmap = DirtyBitmap::new(Bitmap::new(width, height).unwrap());
+        assert!(!bitmap.take_dirty().is_empty());
+        bitmap
+    }
+
+    #[test]
+    fn initially_dirty() {
+        let mut bitmap = DirtyBitmap::from(Bitmap::new(16, 12).unwrap());
+        assert!(bitmap.is_dirty());
+        assert_eq!(bitmap.take_dirty(), [Region::new(Origin::ZERO, 16, 12)]);
+        assert!(!bitmap.is_dirty());
+        assert!(bitmap.take_dirty().is_empty());
+    }
+
+    #[test]
+    fn tracks_set_and_fill() {
+        let mut bitmap = clean(32, 20);
+        bitmap.set(3, 3, false);
+        assert!(!bitmap.is_dirty());
+
+        bitmap.set(3, 3, true);
+        bitmap.set(9, 17, true);
+        assert_eq!(
+            bitmap.take_dirty(),
+            [
+                Region::new(Origin::ZERO, 8, 8),
+                Region::new(Origin::new(8, 16), 8, 4),
+            ]
+        );
+
+        bitmap.set(3, 3, false);
+        bitmap.set(9, 9, true);
+        assert_eq!(
+            bitmap.take_dirty(),
+            [
+                Region::new(Origin::ZERO, 8, 8),
+                Region::new(Origin::new(8, 8), 8, 8),
+            ]
+        );
+
+        bitmap.fill(true);
+        assert_eq!(bitmap.take_dirty(), [Region::new(Origin::ZERO, 32, 20)]);
+        bitmap.fill(true);
+        assert!(!bitmap.is_dirty());
+
+        bitmap.mark_all_dirty();
+        assert!(bitmap.is_dirty());
+    }
+
+    #[test]
+    fn tracks_windows_and_blits() {
+        let mut bitmap = clean(32, 16);
+        let mut window = bitmap.window_mut(4..12, 2..4).unwrap();
+        window.fill(true);
+        assert_eq!(bitmap.take_dirty(), [Region::new(Origin::ZERO, 16, 8)]);
+
+        let mut source = Bitmap::new(8, 2).unwrap();
+        source.fill(true);
+        bitmap.blit(&source, 24, 7);
+        assert_eq!(
+            bitmap.take_dirty(),
+            [Region::new(Origin::new(24, 0), 8, 16)]
+        );
+    }
+
+    #[test]
+    fn commands() {
+        let mut bitmap = clean(16, 16);
+        bitmap.set(15, 15, true);
+        let commands = bitmap.take_commands(CompressionCode::Uncompressed);
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].origin, Origin::new(8, 8));
+        assert_eq!(commands[0].bitmap.data_ref(), [0, 0, 0, 0, 0, 0, 0, 1]);
+        assert!(bitmap
+            .take_commands(CompressionCode::Uncompressed)
+            .is_empty());
+    }
+}
@@ -0,0 +1,339 @@
+use crate::{Bitmap, Grid, GridMut, WindowMut};
+use bitvec::{order::Msb0, slice::BitSlice};
+use embedded_graphics_core::{
+    draw_target::DrawTarget,
+    geometry::{OriginDimensions, Point, Size},
+    pixelcolor::BinaryColor,
+    primitives::Rectangle,
+    Pixel,
+};
+use std::{convert::Infallible, ops::Range};
+
+/// The part of a [Bitmap] that is drawn to, in absolute pixel positions.
+struct Viewport<'t> {
+    bitmap: &'t mut Bitmap,
+    xs: Range<usize>,
+    ys: Range<usize>,
+}
+
+impl Viewport<'_> {
+    /// Translates a point relative to the viewport to an absolute position, if inside.
+    fn position(&self, point: Point) -> Option<(usize, usize)> {
+        let x = usize::try_from(point.x).ok()?;
+        let y = usize::try_from(point.y).ok()?;
+        if x >= self.xs.len() || y >= self.ys.len() {
+            return None;
+        }
+        Some((self.xs.start + x, self.ys.start + y))
+    }
+
+    /// The pixels of the row `y` covered by `width` pixels starting at `x`, clipped to the viewport.
+    ///
+    /// returns: the number of pixels clipped at the start and the remaining pixels
+    fn row_mut(
+        &mut self,
+        x: i32,
+        y: i32,
+        width: u32,
+    ) -> Option<(usize, &mut BitSlice<u8, Msb0>)> {
+        let y = usize::try_from(y).ok().filter(|y| *y < self.ys.len())?;
+        let start = i64::from(x).max(0);
+        let end = (i64::from(x) + i64::from(width))
+            .min(i64::try_from(self.xs.len()).ok()?);
+        if start >= end {
+            return None;
+        }
+        let skip = usize::try_from(start - i64::from(x)).ok()?;
+        let start = usize::try_from(start).ok()?;
+        let end = usize::try_from(end).ok()?;
+        let xs = self.xs.start + start..self.xs.start + end;
+        Some((skip, self.bitmap.row_bits_mut(self.ys.start + y, xs)))
+    }
+
+    fn draw_iter(
+        &mut self,
+        pixels: impl IntoIterator<Item = Pixel<BinaryColor>>,
+    ) {
+        for Pixel(point, color) in pixels {
+            if let Some((x, y)) = self.position(point) {
+                self.bitmap.set(x, y, color.is_on());
+            }
+        }
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: BinaryColor) {
+        for row in 0..area.size.height {
+            let Ok(row) = i32::try_from(row) else {
+                return;
+            };
+            let y = area.top_left.y.saturating_add(row);
+            if let Some((_, bits)) =
+                self.row_mut(area.top_left.x, y, area.size.width)
+            {
+                bits.fill(color.is_on());
+            }
+        }
+    }
+
+    fn fill_contiguous(
+        &mut self,
+        area: &Rectangle,
+        colors: impl IntoIterator<Item = BinaryColor>,
+    ) {
+        let mut colors = colors.into_iter();
+        let width = area.size.width as usize;
+        for row in 0..area.size.height {
+            let Ok(row) = i32::try_from(row) else {
+                return;
+            };
+            let y = area.top_left.y.saturating_add(row);
+            let mut row_colors = colors.by_ref().take(width);
+            if let Some((skip, bits)) =
+                self.row_mut(area.top_left.x, y, area.size.width)
+            {
+                for (mut bit, color) in
+                    bits.iter_mut().zip(row_colors.by_ref().skip(skip))
+                {
+                    bit.set(color.is_on());
+                }
+            }
+            // colors of clipped pixels still have to be consumed
+            row_colors.for_each(drop);
+        }
+    }
+}
+
+fn size(width: usize, height: usize) -> Size {
+    Size::new(
+        u32::try_from(width).unwrap_or(u32::MAX),
+        u32::try_from(height).unwrap_or(u32::MAX),
+    )
+}
+
+impl OriginDimensions for Bitmap {
+    fn size(&self) -> Size {
+        size(self.width(), self.height())
+    }
+}
+
+/// Allows drawing on the bitmap with [embedded-graphics](https://crates.io/crates/embedded-graphics).
+///
+/// Pixels outside of the bitmap are ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// # use servicepoint::*;
+/// use embedded_graphics::{
+///     mono_font::{ascii::FONT_6X10, MonoTextStyle},
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     primitives::{Circle, PrimitiveStyle},
+///     text::Text,
+/// };
+///
+/// let mut bitmap = Bitmap::max_sized();
+/// Circle::new(Point::new(4, 4), 40)
+///     .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 2))
+///     .draw(&mut bitmap)
+///     .unwrap();
+/// Text::new("Hello", Point::new(60, 24), MonoTextStyle::new(&FONT_6X10, BinaryColor::On))
+///     .draw(&mut bitmap)
+///     .unwrap();
+/// assert!(bitmap.any());
+/// ```
+impl DrawTarget for Bitmap {
+    type Color = BinaryColor;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        Viewport::from(self).draw_iter(pixels);
+        Ok(())
+    }
+
+    fn fill_contiguous<I>(
+        &mut self,
+        area: &Rectangle,
+        colors: I,
+    ) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        Viewport::from(self).fill_contiguous(area, colors);
+        Ok(())
+    }
+
+    fn fill_solid(
+        &mut self,
+        area: &Rectangle,
+        color: Self::Color,
+    ) -> Result<(), Self::Error> {
+        Viewport::from(self).fill_solid(area, color);
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.fill(color.is_on());
+        Ok(())
+    }
+}
+
+impl<'t> From<&'t mut Bitmap> for Viewport<'t> {
+    fn from(bitmap: &'t mut Bitmap) -> Self {
+        let (xs, ys) = (0..bitmap.width(), 0..bitmap.height());
+        Self { bitmap, xs, ys }
+    }
+}
+
+/// Runs `f` on a viewport covering the window.
+fn with_viewport<R>(
+    window: &mut WindowMut<'_, bool, Bitmap>,
+    f: impl FnOnce(&mut Viewport<'_>) -> R,
+) -> R {
+    let (xs, ys) = (window.xs(), window.ys());
+    window.with_grid_mut(|bitmap| f(&mut Viewport { bitmap, xs, ys }))
+}
+
+impl OriginDimensions for WindowMut<'_, bool, Bitmap> {
+    fn size(&self) -> Size {
+        size(self.width(), self.height())
+    }
+}
+
+/// Allows drawing on the window with [embedded-graphics](https://crates.io/crates/embedded-graphics).
+///
+/// Positions are relative to the window. Pixels outside of the window are ignored.
+impl DrawTarget for WindowMut<'_, bool, Bitmap> {
+    type Color = BinaryColor;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        // the pixels may be read from other windows into the same bitmap,
+        // so they have to be collected before borrowing it
+        let pixels = pixels.into_iter().collect::<Vec<_>>();
+        with_viewport(self, |viewport| viewport.draw_iter(pixels));
+        Ok(())
+    }
+
+    fn fill_contiguous<I>(
+        &mut self,
+        area: &Rectangle,
+        colors: I,
+    ) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        let colors = colors.into_iter().collect::<Vec<_>>();
+        with_viewport(self, |viewport| {
+            viewport.fill_contiguous(area, colors);
+        });
+        Ok(())
+    }
+
+    fn fill_solid(
+        &mut self,
+        area: &Rectangle,
+        color: Self::Color,
+    ) -> Result<(), Self::Error> {
+        with_viewport(self, |viewport| viewport.fill_solid(area, color));
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.fill(color.is_on());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Bitmap, DataRef, Grid};
+    use embedded_graphics_core::{
+        draw_target::DrawTarget,
+        geometry::{OriginDimensions, Point, Size},
+        pixelcolor::BinaryColor,
+        primitives::Rectangle,
+        Pixel,
+    };
+
+    #[test]
+    fn size() {
+        let mut bitmap = Bitmap::new(16, 3).unwrap();
+        assert_eq!(bitmap.size(), Size::new(16, 3));
+        let window = bitmap.window_mut(2..7, 1..3).unwrap();
+        assert_eq!(window.size(), Size::new(5, 2));
+    }
+
+    #[test]
+    fn draw_iter() {
+        let mut bitmap = Bitmap::new(8, 2).unwrap();
+        bitmap
+            .draw_iter([
+                Pixel(Point::new(0, 0), BinaryColor::On),
+                Pixel(Point::new(7, 1), BinaryColor::On),
+                Pixel(Point::new(-1, 0), BinaryColor::On),
+                Pixel(Point::new(8, 1), BinaryColor::On),
+            ])
+            .unwrap();
+        assert_eq!(bitmap.data_ref(), [0x80, 0x01]);
+    }
+
+    #[test]
+    fn fill_solid_clipped() {
+        let mut bitmap = Bitmap::new(16, 3).unwrap();
+        bitmap
+            .fill_solid(
+                &Rectangle::new(Point::new(-2, 1), Size::new(6, 10)),
+                BinaryColor::On,
+            )
+            .unwrap();
+        assert_eq!(bitmap.data_ref(), [0, 0, 0xF0, 0, 0xF0, 0]);
+        bitmap.clear(BinaryColor::On).unwrap();
+        assert!(bitmap.all());
+    }
+
+    #[test]
+    fn fill_contiguous_clipped() {
+        let mut bitmap = Bitmap::new(8, 2).unwrap();
+        // the first column and the second row are outside
+        let colors = [true, true, false, false, true, true]
+            .map(BinaryColor::from)
+            .into_iter()
+            .cycle()
+            .take(9 * 2);
+        bitmap
+            .fill_contiguous(
+                &Rectangle::new(Point::new(-1, 1), Size::new(9, 2)),
+                colors,
+            )
+            .unwrap();
+        assert_eq!(bitmap.data_ref(), [0x00, 0b1001_1110]);
+    }
+
+    #[test]
+    fn window() {
+        let mut bitmap = Bitmap::new(16, 4).unwrap();
+        let mut window = bitmap.window_mut(3..9, 1..3).unwrap();
+        window
+            .fill_solid(
+                &Rectangle::new(Point::new(1, 1), Size::new(10, 10)),
+                BinaryColor::On,
+            )
+            .unwrap();
+        window
+            .draw_iter([
+                Pixel(Point::new(0, 0), BinaryColor::On),
+                Pixel(Point::new(6, 0), BinaryColor::On),
+            ])
+            .unwrap();
+        assert_eq!(bitmap.data_ref(), [0, 0, 0x10, 0, 0x0F, 0x80, 0, 0]);
+        assert_eq!(bitmap.count_ones(), 6);
+        assert!(bitmap.get(8, 2));
+    }
+}
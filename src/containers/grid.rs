@@ -1,4 +1,4 @@
-use crate::SetValueSeriesError;
+use crate::{containers::ClippedArea, SetValueSeriesError};
 
 /// A two-dimensional readonly grid of `T`
 pub trait Grid<T> {
@@ -127,7 +127,10 @@ pub trait GridMut<T: Clone>: Grid<T> {
     /// The grids have to match in size exactly.
     ///
     /// For 1D slices the equivalent would be `*slice = other_slice`.
-    fn deref_assign<O: Grid<T>>(&mut self, other: &O) {
+    fn deref_assign<O: Grid<T>>(&mut self, other: &O)
+    where
+        Self: Sized,
+    {
         let width = self.width();
         let height = self.height();
         assert_eq!(
@@ -151,6 +154,37 @@ pub trait GridMut<T: Clone>: Grid<T> {
         }
     }
 
+    /// Copies the provided grid into this grid at the specified position.
+    ///
+    /// In contrast to [`GridMut::deref_assign`], the grids do not have to match in size.
+    /// The position can be negative or out of bounds, only overlapping cells are copied.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: the grid to copy from
+    /// - `x` and `y`: position of the top left corner of `source` in this grid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use servicepoint::*;
+    /// let mut grid = CharGrid::new(TILE_WIDTH, TILE_HEIGHT);
+    /// let text = CharGrid::from("Hello");
+    /// // only "llo" ends up in the grid
+    /// grid.blit(&text, -2, 3);
+    /// ```
+    fn blit<O: Grid<T>>(&mut self, source: &O, x: isize, y: isize)
+    where
+        Self: Sized,
+    {
+        let Some(area) = ClippedArea::new(self, source, x, y) else {
+            return;
+        };
+        for (source_x, source_y, target_x, target_y) in area.positions() {
+            self.set(target_x, target_y, source.get(source_x, source_y));
+        }
+    }
+
     /// Overwrites a column in the grid.
     ///
     /// Returns [Err] if x is out of bounds or `col` is not of the correct size.
@@ -0,0 +1,269 @@
+use crate::{
+    Bitmap, Brightness, BrightnessGrid, ByteGrid, DitheredFrames, Grid,
+    GridMut, LoadBitmapError, TILE_SIZE,
+};
+use image::{DynamicImage, GrayImage, Luma};
+
+#[allow(
+    clippy::cast_possible_truncation,
+    reason = "grids that large do not fit into memory as images anyway"
+)]
+fn image_size<T>(grid: &impl Grid<T>) -> (u32, u32) {
+    (grid.width() as u32, grid.height() as u32)
+}
+
+impl Bitmap {
+    /// Converts a grayscale image to a bitmap by comparing each pixel to `threshold`.
+    ///
+    /// Pixels at least as bright as `threshold` are on.
+    /// For other image types, use [`Self::from_dynamic_image_threshold`].
+    ///
+    /// returns: an error if the width of the image is not a multiple of 8
+    pub fn from_image_threshold(
+        image: &GrayImage,
+        threshold: u8,
+    ) -> Result<Self, LoadBitmapError> {
+        let (width, height) = (image.width() as usize, image.height() as usize);
+        let mut bitmap =
+            Self::new(width, height).ok_or(LoadBitmapError::InvalidWidth)?;
+        for (x, y, Luma([value])) in image.enumerate_pixels() {
+            bitmap.set(x as usize, y as usize, *value >= threshold);
+        }
+        Ok(bitmap)
+    }
+
+    /// Converts a grayscale image to a bitmap using ordered dithering,
+    /// so gray areas are approximated by patterns.
+    ///
+    /// This is a single frame of [`DitheredFrames`], use that for even more gray levels.
+    ///
+    /// returns: an error if the width of the image is not a multiple of 8
+    pub fn from_image_dithered(
+        image: &GrayImage,
+    ) -> Result<Self, LoadBitmapError> {
+        DitheredFrames::with_frame_count(&ByteGrid::from(image), 1)
+            .and_then(|mut frames| frames.next())
+            .ok_or(LoadBitmapError::InvalidWidth)
+    }
+
+    /// Like [`Self::from_image_threshold`], using the luminance of the image.
+    ///
+    /// returns: an error if the width of the image is not a multiple of 8
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use servicepoint::*;
+    /// # let connection = FakeConnection;
+    /// let image = image::DynamicImage::new_rgb8(64, 48);
+    /// let bitmap = Bitmap::from_dynamic_image_threshold(&image, 128).unwrap();
+    /// connection.send_command(BitmapCommand::from(bitmap)).unwrap();
+    /// ```
+    pub fn from_dynamic_image_threshold(
+        image: &DynamicImage,
+        threshold: u8,
+    ) -> Result<Self, LoadBitmapError> {
+        Self::from_image_threshold(&image.to_luma8(), threshold)
+    }
+
+    /// Like [`Self::from_image_dithered`], using the luminance of the image.
+    ///
+    /// returns: an error if the width of the image is not a multiple of 8
+    pub fn from_dynamic_image_dithered(
+        image: &DynamicImage,
+    ) -> Result<Self, LoadBitmapError> {
+        Self::from_image_dithered(&image.to_luma8())
+    }
+}
+
+impl From<&Bitmap> for GrayImage {
+    /// Converts the bitmap to an image with black and white pixels.
+    fn from(value: &Bitmap) -> Self {
+        let (width, height) = image_size(value);
+        GrayImage::from_fn(width, height, |x, y| {
+            Luma([if value.get(x as usize, y as usize) {
+                u8::MAX
+            } else {
+                0
+            }])
+        })
+    }
+}
+
+impl From<&Bitmap> for DynamicImage {
+    fn from(value: &Bitmap) -> Self {
+        DynamicImage::ImageLuma8(value.into())
+    }
+}
+
+impl From<&GrayImage> for ByteGrid {
+    /// Copies the pixel values of the image.
+    fn from(value: &GrayImage) -> Self {
+        Self::from_raw_parts_unchecked(
+            value.width() as usize,
+            value.height() as usize,
+            value.as_raw().clone(),
+        )
+    }
+}
+
+impl From<&DynamicImage> for ByteGrid {
+    /// Copies the luminance of the image.
+    fn from(value: &DynamicImage) -> Self {
+        Self::from(&value.to_luma8())
+    }
+}
+
+impl From<&ByteGrid> for GrayImage {
+    fn from(value: &ByteGrid) -> Self {
+        let (width, height) = image_size(value);
+        GrayImage::from_fn(width, height, |x, y| {
+            Luma([value.get(x as usize, y as usize)])
+        })
+    }
+}
+
+impl From<&ByteGrid> for DynamicImage {
+    fn from(value: &ByteGrid) -> Self {
+        DynamicImage::ImageLuma8(value.into())
+    }
+}
+
+impl From<&GrayImage> for BrightnessGrid {
+    /// Uses the average luminance of the pixels in each tile as its brightness.
+    ///
+    /// The resulting grid has one cell per tile, incomplete tiles at the edges included.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use servicepoint::*;
+    /// let image = image::GrayImage::from_pixel(16, 12, image::Luma([255]));
+    /// let grid = BrightnessGrid::from(&image);
+    /// assert_eq!((grid.width(), grid.height()), (2, 2));
+    /// assert_eq!(grid.get(1, 1), Brightness::MAX);
+    /// ```
+    fn from(value: &GrayImage) -> Self {
+        let width = (value.width() as usize + TILE_SIZE - 1) / TILE_SIZE;
+        let height = (value.height() as usize + TILE_SIZE - 1) / TILE_SIZE;
+        let mut sums = vec![(0u64, 0u64); width * height];
+        for (x, y, Luma([luminance])) in value.enumerate_pixels() {
+            let tile = y as usize / TILE_SIZE * width + x as usize / TILE_SIZE;
+            sums[tile].0 += u64::from(*luminance);
+            sums[tile].1 += 1;
+        }
+
+        let max = u64::from(u8::from(Brightness::MAX));
+        let brightnesses = sums
+            .into_iter()
+            .map(|(sum, count)| {
+                let divisor = count * u64::from(u8::MAX);
+                let level = (sum * max + divisor / 2) / divisor;
+                Brightness::saturating_from(
+                    u8::try_from(level).unwrap_or(u8::MAX),
+                )
+            })
+            .collect();
+        Self::from_raw_parts_unchecked(width, height, brightnesses)
+    }
+}
+
+impl From<&DynamicImage> for BrightnessGrid {
+    /// Like the conversion from [`GrayImage`], using the luminance of the image.
+    fn from(value: &DynamicImage) -> Self {
+        Self::from(&value.to_luma8())
+    }
+}
+
+impl From<&BrightnessGrid> for GrayImage {
+    /// Creates an image with one pixel per tile, scaling the brightness levels to the full range.
+    fn from(value: &BrightnessGrid) -> Self {
+        let (width, height) = image_size(value);
+        let max = u16::from(u8::from(Brightness::MAX));
+        GrayImage::from_fn(width, height, |x, y| {
+            let level = u16::from(u8::from(value.get(x as usize, y as usize)));
+            let luminance = (level * u16::from(u8::MAX) + max / 2) / max;
+            Luma([u8::try_from(luminance).unwrap_or(u8::MAX)])
+        })
+    }
+}
+
+impl From<&BrightnessGrid> for DynamicImage {
+    fn from(value: &BrightnessGrid) -> Self {
+        DynamicImage::ImageLuma8(value.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Bitmap, Brightness, BrightnessGrid, ByteGrid, DataRef, Grid, GridMut,
+        LoadBitmapError,
+    };
+    use image::{DynamicImage, GrayImage, Luma};
+
+    #[test]
+    fn bitmap_threshold() {
+        let image = GrayImage::from_fn(8, 2, |x, y| Luma([(x * 32 + y) as u8]));
+        let bitmap = Bitmap::from_image_threshold(&image, 128).unwrap();
+        assert_eq!(bitmap.data_ref(), [0x0F, 0x0F]);
+        assert_eq!(
+            Bitmap::from_image_threshold(&GrayImage::new(7, 1), 128),
+            Err(LoadBitmapError::InvalidWidth)
+        );
+
+        let image = GrayImage::from(&bitmap);
+        assert_eq!(image.get_pixel(3, 1), &Luma([0]));
+        assert_eq!(image.get_pixel(4, 1), &Luma([255]));
+    }
+
+    #[test]
+    fn bitmap_dithered() {
+        let image = GrayImage::from_pixel(8, 8, Luma([128]));
+        let bitmap = Bitmap::from_image_dithered(&image).unwrap();
+        assert_eq!(bitmap.iter().filter(|pixel| **pixel).count(), 32);
+
+        let image = GrayImage::from_pixel(8, 8, Luma([255]));
+        assert!(Bitmap::from_image_dithered(&image).unwrap().all());
+    }
+
+    #[test]
+    fn bitmap_from_dynamic_image() {
+        let mut image = DynamicImage::new_rgb8(8, 1);
+        image
+            .as_mut_rgb8()
+            .unwrap()
+            .put_pixel(2, 0, image::Rgb([255; 3]));
+        let bitmap = Bitmap::from_dynamic_image_threshold(&image, 128).unwrap();
+        assert_eq!(bitmap.data_ref(), [0x20]);
+        assert_eq!(
+            Bitmap::from_dynamic_image_dithered(&DynamicImage::new_rgb8(7, 1)),
+            Err(LoadBitmapError::InvalidWidth)
+        );
+    }
+
+    #[test]
+    fn byte_grid_round_trip() {
+        let grid = ByteGrid::load(2, 2, &[0, 64, 128, 255]).unwrap();
+        let image = DynamicImage::from(&grid);
+        assert_eq!(image.width(), 2);
+        assert_eq!(ByteGrid::from(&image), grid);
+    }
+
+    #[test]
+    fn brightness_per_tile() {
+        // left tile half lit, right tile fully lit but cut off
+        let image = GrayImage::from_fn(12, 8, |x, y| {
+            Luma([if x >= 8 || y < 4 { 255 } else { 0 }])
+        });
+        let grid = BrightnessGrid::from(&image);
+        assert_eq!((grid.width(), grid.height()), (2, 1));
+        assert_eq!(grid.get(0, 0), Brightness::saturating_from(6));
+        assert_eq!(grid.get(1, 0), Brightness::MAX);
+
+        let mut grid = grid;
+        grid.set(0, 0, Brightness::MIN);
+        let image = GrayImage::from(&grid);
+        assert_eq!(image.as_raw(), &[0, 255]);
+    }
+}
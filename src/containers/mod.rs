@@ -1,24 +1,46 @@
 mod bit_vec;
 mod bitmap;
+mod bitmap_ops;
+mod bitmap_tiles;
+mod bitmap_transform;
+mod blit;
 mod brightness_grid;
 mod byte_grid;
 mod char_grid;
 mod char_grid_ext;
 mod cp437_grid;
 mod data_ref;
+mod dirty_bitmap;
+#[cfg(feature = "embedded_graphics")]
+mod draw_target;
 mod grid;
+#[cfg(feature = "image")]
+mod image_interop;
+mod resize;
+mod text_cells;
+mod tile_grid;
+mod tile_mut;
 mod value_grid;
 mod window;
 
 pub use bit_vec::{bitvec, DisplayBitVec};
 pub use bitmap::{Bitmap, LoadBitmapError};
+pub use bitmap_tiles::{TileBytes, TileBytesMut};
+pub use bitmap_transform::ShiftMode;
+pub use blit::BoolGridMutExt;
+pub(crate) use blit::ClippedArea;
 pub use brightness_grid::BrightnessGrid;
 pub use byte_grid::ByteGrid;
 pub use char_grid::CharGrid;
 pub use char_grid_ext::{CharGridExt, CharGridMutExt};
 pub use cp437_grid::{Cp437Grid, InvalidCharError};
 pub use data_ref::DataRef;
+pub use dirty_bitmap::DirtyBitmap;
 pub use grid::{Grid, GridMut};
+pub use resize::ResizeGridError;
+pub(crate) use text_cells::{glyphs, row_cells, text_width, Glyph};
+pub use tile_grid::TileGrid;
+pub use tile_mut::{BitmapTileMut, TileMut, TileRow};
 pub use value_grid::{
     SetValueSeriesError, TryLoadValueGridError, Value, ValueGrid,
 };
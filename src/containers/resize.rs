@@ -0,0 +1,322 @@
+use crate::{
+    containers::absolute_bounds_to_abs_range, BinaryOperation, Bitmap, Grid,
+    GridMut, Value, ValueGrid,
+};
+use std::ops::{Range, RangeBounds};
+
+/// Errors that can happen when changing the size of a grid.
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum ResizeGridError {
+    /// The provided ranges are not inside of the grid.
+    #[error("The provided ranges are not inside of the grid.")]
+    OutOfBounds,
+    /// The resulting width of a [Bitmap] would not be divisible by 8.
+    #[error("The resulting width {0} is not divisible by 8.")]
+    InvalidWidth(usize),
+}
+
+/// Resolves the provided ranges to absolute ranges inside of `grid`.
+fn area<T>(
+    grid: &impl Grid<T>,
+    xs: impl RangeBounds<usize>,
+    ys: impl RangeBounds<usize>,
+) -> Result<(Range<usize>, Range<usize>), ResizeGridError> {
+    let xs = absolute_bounds_to_abs_range(xs, grid.width())
+        .filter(|xs| xs.start <= xs.end)
+        .ok_or(ResizeGridError::OutOfBounds)?;
+    let ys = absolute_bounds_to_abs_range(ys, grid.height())
+        .filter(|ys| ys.start <= ys.end)
+        .ok_or(ResizeGridError::OutOfBounds)?;
+    Ok((xs, ys))
+}
+
+#[allow(
+    clippy::cast_possible_wrap,
+    reason = "grids are never larger than isize::MAX"
+)]
+fn offset(value: usize) -> isize {
+    value as isize
+}
+
+impl<T: Value> ValueGrid<T> {
+    /// Creates a grid of the specified size with the contents of this grid moved by `x` and `y`.
+    ///
+    /// Cells that have no source are set to `fill`.
+    fn reframed(
+        &self,
+        width: usize,
+        height: usize,
+        x: isize,
+        y: isize,
+        fill: T,
+    ) -> Self {
+        let mut result = Self::new(width, height);
+        result.fill(fill);
+        result.blit(self, x, y);
+        result
+    }
+
+    /// Changes the size of the grid, keeping the contents at the top left corner.
+    ///
+    /// New cells are set to `fill`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use servicepoint::*;
+    /// let mut grid = CharGrid::from("ab\ncd");
+    /// grid.resize(3, 1, '.');
+    /// assert_eq!(String::from(grid), "ab.");
+    /// ```
+    pub fn resize(&mut self, width: usize, height: usize, fill: T) {
+        *self = self.reframed(width, height, 0, 0, fill);
+    }
+
+    /// Adds the specified amount of cells on each side of the grid.
+    ///
+    /// New cells are set to `fill`.
+    pub fn pad(
+        &mut self,
+        left: usize,
+        top: usize,
+        right: usize,
+        bottom: usize,
+        fill: T,
+    ) {
+        *self = self.reframed(
+            left + self.width() + right,
+            top + self.height() + bottom,
+            offset(left),
+            offset(top),
+            fill,
+        );
+    }
+
+    /// Creates a copy of the specified part of the grid.
+    ///
+    /// returns: an error if the ranges are out of bounds
+    pub fn extract(
+        &self,
+        xs: impl RangeBounds<usize>,
+        ys: impl RangeBounds<usize>,
+    ) -> Result<Self, ResizeGridError> {
+        let (xs, ys) = area(self, xs, ys)?;
+        Ok(self.reframed(
+            xs.len(),
+            ys.len(),
+            -offset(xs.start),
+            -offset(ys.start),
+            T::default(),
+        ))
+    }
+
+    /// Reduces the grid to the specified part.
+    ///
+    /// returns: an error if the ranges are out of bounds, in which case the grid is not changed
+    pub fn crop(
+        &mut self,
+        xs: impl RangeBounds<usize>,
+        ys: impl RangeBounds<usize>,
+    ) -> Result<(), ResizeGridError> {
+        *self = self.extract(xs, ys)?;
+        Ok(())
+    }
+
+    /// Like [`Self::crop`], but consuming the grid.
+    pub fn into_subgrid(
+        mut self,
+        xs: impl RangeBounds<usize>,
+        ys: impl RangeBounds<usize>,
+    ) -> Result<Self, ResizeGridError> {
+        self.crop(xs, ys)?;
+        Ok(self)
+    }
+}
+
+impl Bitmap {
+    /// Creates a bitmap of the specified size with the contents of this bitmap moved by `x` and `y`.
+    ///
+    /// Pixels that have no source are set to `fill`.
+    fn reframed(
+        &self,
+        width: usize,
+        height: usize,
+        x: isize,
+        y: isize,
+        fill: bool,
+    ) -> Result<Self, ResizeGridError> {
+        let mut result = Self::new(width, height)
+            .ok_or(ResizeGridError::InvalidWidth(width))?;
+        result.fill(fill);
+        result.blit_bitmap(self, x, y, BinaryOperation::Overwrite);
+        Ok(result)
+    }
+
+    /// Changes the size of the bitmap, keeping the contents at the top left corner.
+    ///
+    /// New pixels are set to `fill`.
+    ///
+    /// returns: an error if the width is not a multiple of 8, in which case the bitmap is not changed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use servicepoint::*;
+    /// let mut bitmap = Bitmap::new(8, 2).unwrap();
+    /// bitmap.resize(16, 1, true).unwrap();
+    /// assert_eq!(bitmap.data_ref(), [0x00, 0xFF]);
+    /// assert!(bitmap.resize(12, 1, true).is_err());
+    /// ```
+    pub fn resize(
+        &mut self,
+        width: usize,
+        height: usize,
+        fill: bool,
+    ) -> Result<(), ResizeGridError> {
+        *self = self.reframed(width, height, 0, 0, fill)?;
+        Ok(())
+    }
+
+    /// Adds the specified amount of pixels on each side of the bitmap.
+    ///
+    /// The sides do not need to be multiples of 8 on their own, only the resulting width.
+    ///
+    /// returns: an error if the resulting width is not a multiple of 8,
+    /// in which case the bitmap is not changed
+    pub fn pad(
+        &mut self,
+        left: usize,
+        top: usize,
+        right: usize,
+        bottom: usize,
+        fill: bool,
+    ) -> Result<(), ResizeGridError> {
+        *self = self.reframed(
+            left + self.width() + right,
+            top + self.height() + bottom,
+            offset(left),
+            offset(top),
+            fill,
+        )?;
+        Ok(())
+    }
+
+    /// Creates a copy of the specified part of the bitmap.
+    ///
+    /// The part does not need to start at a multiple of 8, but its width has to be one.
+    ///
+    /// returns: an error if the ranges are out of bounds or the width is not a multiple of 8
+    pub fn extract(
+        &self,
+        xs: impl RangeBounds<usize>,
+        ys: impl RangeBounds<usize>,
+    ) -> Result<Self, ResizeGridError> {
+        let (xs, ys) = area(self, xs, ys)?;
+        self.reframed(
+            xs.len(),
+            ys.len(),
+            -offset(xs.start),
+            -offset(ys.start),
+            false,
+        )
+    }
+
+    /// Reduces the bitmap to the specified part.
+    ///
+    /// returns: an error if the ranges are out of bounds or the width is not a multiple of 8,
+    /// in which case the bitmap is not changed
+    pub fn crop(
+        &mut self,
+        xs: impl RangeBounds<usize>,
+        ys: impl RangeBounds<usize>,
+    ) -> Result<(), ResizeGridError> {
+        *self = self.extract(xs, ys)?;
+        Ok(())
+    }
+
+    /// Like [`Self::crop`], but consuming the bitmap.
+    pub fn into_subgrid(
+        mut self,
+        xs: impl RangeBounds<usize>,
+        ys: impl RangeBounds<usize>,
+    ) -> Result<Self, ResizeGridError> {
+        self.crop(xs, ys)?;
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Bitmap, ByteGrid, CharGrid, DataRef, Grid, ResizeGridError};
+
+    #[test]
+    fn resize_value_grid() {
+        let mut grid = ByteGrid::load(2, 2, &[1, 2, 3, 4]).unwrap();
+        grid.resize(3, 3, 9);
+        assert_eq!(grid.data_ref(), [1, 2, 9, 3, 4, 9, 9, 9, 9]);
+        grid.resize(1, 2, 0);
+        assert_eq!(grid.data_ref(), [1, 3]);
+        grid.resize(0, 5, 0);
+        assert_eq!((grid.width(), grid.height()), (0, 5));
+    }
+
+    #[test]
+    fn pad_and_crop_value_grid() {
+        let mut grid = CharGrid::from("ab\ncd");
+        grid.pad(1, 0, 2, 1, '.');
+        assert_eq!(String::from(&grid), ".ab..\n.cd..\n.....");
+
+        let extracted = grid.extract(1..3, ..2).unwrap();
+        assert_eq!(String::from(extracted), "ab\ncd");
+        assert_eq!(grid.extract(4..6, ..), Err(ResizeGridError::OutOfBounds));
+
+        assert_eq!(grid.crop(.., 4..), Err(ResizeGridError::OutOfBounds));
+        assert_eq!(grid.height(), 3);
+        grid.crop(2..=3, 1..2).unwrap();
+        assert_eq!(String::from(&grid), "d.");
+
+        let grid = grid.into_subgrid(1.., ..).unwrap();
+        assert_eq!(grid.data_ref(), ['.']);
+    }
+
+    #[test]
+    fn resize_bitmap() {
+        let mut bitmap = Bitmap::load(8, 2, &[0x0F, 0xF0]).unwrap();
+        assert_eq!(
+            bitmap.resize(4, 2, false),
+            Err(ResizeGridError::InvalidWidth(4))
+        );
+        assert_eq!(bitmap.data_ref(), [0x0F, 0xF0]);
+
+        bitmap.resize(16, 3, true).unwrap();
+        assert_eq!(bitmap.data_ref(), [0x0F, 0xFF, 0xF0, 0xFF, 0xFF, 0xFF]);
+        bitmap.resize(8, 1, false).unwrap();
+        assert_eq!(bitmap.data_ref(), [0x0F]);
+    }
+
+    #[test]
+    fn pad_and_crop_bitmap() {
+        let mut bitmap = Bitmap::load(8, 1, &[0xFF]).unwrap();
+        assert_eq!(
+            bitmap.pad(1, 1, 1, 1, false),
+            Err(ResizeGridError::InvalidWidth(10))
+        );
+        bitmap.pad(3, 1, 5, 0, false).unwrap();
+        assert_eq!(bitmap.data_ref(), [0x00, 0x00, 0x1F, 0xE0]);
+
+        // not aligned to bytes
+        let extracted = bitmap.extract(3..11, 1..).unwrap();
+        assert_eq!(extracted.data_ref(), [0xFF]);
+        assert_eq!(
+            bitmap.extract(3..10, ..),
+            Err(ResizeGridError::InvalidWidth(7))
+        );
+        assert_eq!(bitmap.extract(..24, ..), Err(ResizeGridError::OutOfBounds));
+
+        bitmap.crop(7..15, ..).unwrap();
+        assert_eq!(bitmap.data_ref(), [0x00, 0xF0]);
+        let bitmap = bitmap.into_subgrid(.., 1..).unwrap();
+        assert!(bitmap.get(0, 0));
+        assert_eq!(bitmap.height(), 1);
+    }
+}
@@ -0,0 +1,83 @@
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// The cell following a wide character, which covers two cells.
+pub(crate) const WIDE_CHAR_PADDING: char = ' ';
+
+/// A grapheme cluster reduced to what fits into a cell of a [`crate::CharGrid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Glyph {
+    /// the base character of the cluster
+    pub char: char,
+    /// the amount of cells the cluster covers in a row, 1 or 2
+    pub width: usize,
+}
+
+/// Splits the text into grapheme clusters.
+///
+/// The text is normalized first, so combining sequences become precomposed characters where possible.
+/// Marks that cannot be combined with the base character are dropped.
+/// Clusters without width, e.g. control characters, still take up one cell.
+pub(crate) fn glyphs(text: &str) -> Vec<Glyph> {
+    let text = text.nfc().collect::<String>();
+    text.graphemes(true)
+        .filter_map(|cluster| {
+            let char = cluster.chars().next()?;
+            let width = cluster.width().clamp(1, 2);
+            Some(Glyph { char, width })
+        })
+        .collect()
+}
+
+/// The amount of cells the text covers in a row.
+pub(crate) fn text_width(text: &str) -> usize {
+    glyphs(text).iter().map(|glyph| glyph.width).sum()
+}
+
+/// The contents of the cells of a row showing the text.
+///
+/// Wide characters are followed by [`WIDE_CHAR_PADDING`].
+pub(crate) fn row_cells(text: &str) -> Vec<char> {
+    let mut cells = Vec::with_capacity(text.len());
+    for glyph in glyphs(text) {
+        cells.push(glyph.char);
+        if glyph.width > 1 {
+            cells.push(WIDE_CHAR_PADDING);
+        }
+    }
+    cells
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn combining_marks() {
+        // e + combining acute accent
+        assert_eq!(row_cells("e\u{301}x"), ['é', 'x']);
+        // q + combining acute accent has no precomposed form
+        assert_eq!(row_cells("q\u{301}"), ['q']);
+        assert_eq!(text_width("Cafe\u{301}"), 4);
+    }
+
+    #[test]
+    fn wide_chars() {
+        assert_eq!(row_cells("中a"), ['中', WIDE_CHAR_PADDING, 'a']);
+        assert_eq!(text_width("中文"), 4);
+        assert_eq!(
+            glyphs("\0中"),
+            [
+                Glyph {
+                    char: '\0',
+                    width: 1
+                },
+                Glyph {
+                    char: '中',
+                    width: 2
+                }
+            ]
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{
+    containers::bitmap_tiles::{TileBytes, TileBytesMut},
+    Bitmap, Brightness, BrightnessGrid, DataRef, Grid, Origin, Tiles,
+};
+
+/// A view that pairs each tile of a [Bitmap] with its cell in a [`BrightnessGrid`].
+///
+/// This is useful for effects that work per tile, e.g. dimming tiles that have no pixels on.
+///
+/// # Examples
+///
+/// ```rust
+/// # use servicepoint::*;
+/// let mut bitmap = Bitmap::max_sized();
+/// let mut brightness = BrightnessGrid::new(TILE_WIDTH, TILE_HEIGHT);
+/// bitmap.set(0, 0, true);
+///
+/// let mut tiles = TileGrid::new(&mut bitmap, &mut brightness).unwrap();
+/// for (_, pixels, brightness) in tiles.iter_mut() {
+///     *brightness = if pixels.iter().any(|row| **row != 0) {
+///         Brightness::MAX
+///     } else {
+///         Brightness::MIN
+///     };
+/// }
+/// assert_eq!(brightness.get(0, 0), Brightness::MAX);
+/// assert_eq!(brightness.get(1, 0), Brightness::MIN);
+/// ```
+#[derive(Debug)]
+pub struct TileGrid<'t> {
+    bitmap: &'t mut Bitmap,
+    brightness: &'t mut BrightnessGrid,
+}
+
+impl<'t> TileGrid<'t> {
+    /// Creates a new view over the provided bitmap and brightness grid.
+    ///
+    /// returns: None if the size of the brightness grid is not the number of complete tiles
+    /// in the bitmap.
+    #[must_use]
+    pub fn new(
+        bitmap: &'t mut Bitmap,
+        brightness: &'t mut BrightnessGrid,
+    ) -> Option<Self> {
+        if bitmap.tile_width() != brightness.width()
+            || bitmap.tile_height() != brightness.height()
+        {
+            return None;
+        }
+        Some(Self { bitmap, brightness })
+    }
+
+    /// The number of tiles in the width direction.
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.brightness.width()
+    }
+
+    /// The number of tiles in the height direction.
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.brightness.height()
+    }
+
+    /// The underlying bitmap.
+    #[must_use]
+    pub fn bitmap(&self) -> &Bitmap {
+        self.bitmap
+    }
+
+    /// The underlying brightness grid.
+    #[must_use]
+    pub fn brightness(&self) -> &BrightnessGrid {
+        self.brightness
+    }
+
+    /// Gets the pixels and brightness of the tile at the specified position.
+    ///
+    /// returns: None if the tile is out of bounds
+    #[must_use]
+    pub fn get(
+        &self,
+        origin: Origin<Tiles>,
+    ) -> Option<(TileBytes, Brightness)> {
+        let brightness = self.brightness.get_optional(origin.x, origin.y)?;
+        Some((self.bitmap.tile(origin)?, brightness))
+    }
+
+    /// Iterates over all tiles with their brightness, row by row.
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = (Origin<Tiles>, TileBytes, Brightness)> + '_ {
+        self.bitmap
+            .tiles()
+            .zip(self.brightness.iter())
+            .map(|((origin, pixels), brightness)| (origin, pixels, *brightness))
+    }
+
+    /// Iterates over all tiles with their brightness, row by row, allowing to change both.
+    pub fn iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = (Origin<Tiles>, TileBytesMut<'_>, &mut Brightness)>
+           + use<'_> {
+        self.bitmap
+            .tiles_mut()
+            .zip(self.brightness.data_ref_mut())
+            .map(|((origin, pixels), brightness)| (origin, pixels, brightness))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Bitmap, Brightness, BrightnessGrid, Grid, GridMut, Origin, TileGrid,
+    };
+
+    #[test]
+    fn size_mismatch() {
+        let mut bitmap = Bitmap::new(16, 12).unwrap();
+        let mut brightness = BrightnessGrid::new(2, 2);
+        assert!(TileGrid::new(&mut bitmap, &mut brightness).is_none());
+        let mut brightness = BrightnessGrid::new(2, 1);
+        assert!(TileGrid::new(&mut bitmap, &mut brightness).is_some());
+    }
+
+    #[test]
+    fn pairs_tiles_with_brightness() {
+        let mut bitmap = Bitmap::new(16, 16).unwrap();
+        let mut brightness = BrightnessGrid::new(2, 2);
+        bitmap.set(8, 8, true);
+        brightness.set(1, 1, Brightness::MIN);
+
+        let mut tiles = TileGrid::new(&mut bitmap, &mut brightness).unwrap();
+        assert_eq!((tiles.width(), tiles.height()), (2, 2));
+        assert_eq!(
+            tiles.get(Origin::new(1, 1)),
+            Some(([0x80, 0, 0, 0, 0, 0, 0, 0], Brightness::MIN))
+        );
+        assert!(tiles.get(Origin::new(2, 1)).is_none());
+
+        for (origin, pixels, brightness) in tiles.iter_mut() {
+            if origin.x == 0 {
+                *pixels[7] = 0xFF;
+                *brightness = Brightness::saturating_from(5);
+            }
+        }
+        let lit: Vec<_> = tiles
+            .iter()
+            .map(|(_, pixels, brightness)| (pixels[7], u8::from(brightness)))
+            .collect();
+        assert_eq!(lit, [(0xFF, 5), (0, 11), (0xFF, 5), (0, 0)]);
+        assert!(tiles.bitmap().get(0, 7));
+        assert_eq!(
+            tiles.brightness().get(0, 1),
+            Brightness::saturating_from(5)
+        );
+    }
+}
@@ -0,0 +1,313 @@
+use crate::{Bitmap, DataRef, Grid, GridMut, Value, ValueGrid};
+use bitvec::{order::Msb0, slice::BitSlice, store::BitStore};
+use std::marker::PhantomData;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// One row of a [`TileMut`].
+///
+/// This trait is sealed, it is implemented for the rows of [`ValueGrid`] and [`Bitmap`] tiles only.
+pub trait TileRow<T>: sealed::Sealed {
+    /// Get the value at the specified position in the row.
+    fn get(&self, x: usize) -> T;
+
+    /// Set the value at the specified position in the row.
+    fn set(&mut self, x: usize, value: T);
+}
+
+impl<T> sealed::Sealed for &mut [T] {}
+
+impl<T: Copy> TileRow<T> for &mut [T] {
+    fn get(&self, x: usize) -> T {
+        self[x]
+    }
+
+    fn set(&mut self, x: usize, value: T) {
+        self[x] = value;
+    }
+}
+
+/// A row of pixels that may share its first and last byte with other rows.
+type BitRow<'t> = &'t mut BitSlice<<u8 as BitStore>::Alias, Msb0>;
+
+impl sealed::Sealed for BitRow<'_> {}
+
+impl TileRow<bool> for BitRow<'_> {
+    fn get(&self, x: usize) -> bool {
+        self[x]
+    }
+
+    fn set(&mut self, x: usize, value: bool) {
+        BitSlice::set(self, x, value);
+    }
+}
+
+/// A mutable view into a rectangular part of a grid that does not overlap with any other tile.
+///
+/// Each tile only holds the rows of the grid it covers,
+/// so tiles can be moved to other threads, e.g. to render multiple parts of the screen in parallel.
+///
+/// Create tiles with [`ValueGrid::split_into_tiles_mut`] or [`Bitmap::split_into_tiles_mut`].
+#[derive(Debug)]
+pub struct TileMut<'t, T: Copy, R: TileRow<T> = &'t mut [T]> {
+    rows: Vec<R>,
+    x: usize,
+    y: usize,
+    width: usize,
+    phantom: PhantomData<&'t mut T>,
+}
+
+/// A tile of a [`Bitmap`], see [`Bitmap::split_into_tiles_mut`].
+pub type BitmapTileMut<'t> = TileMut<'t, bool, BitRow<'t>>;
+
+impl<T: Copy, R: TileRow<T>> TileMut<'_, T, R> {
+    /// The position of the top left cell of the tile in the grid it was split from.
+    #[must_use]
+    pub fn position(&self) -> (usize, usize) {
+        (self.x, self.y)
+    }
+}
+
+impl<T: Copy, R: TileRow<T>> Grid<T> for TileMut<'_, T, R> {
+    fn get_optional(&self, x: usize, y: usize) -> Option<T> {
+        if x >= self.width {
+            return None;
+        }
+        self.rows.get(y).map(|row| row.get(x))
+    }
+
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.rows.len()
+    }
+}
+
+impl<T: Copy, R: TileRow<T>> GridMut<T> for TileMut<'_, T, R> {
+    fn set_optional(&mut self, x: usize, y: usize, value: T) -> bool {
+        if x >= self.width {
+            return false;
+        }
+        match self.rows.get_mut(y) {
+            Some(row) => {
+                row.set(x, value);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn fill(&mut self, value: T) {
+        for row in &mut self.rows {
+            for x in 0..self.width {
+                row.set(x, value);
+            }
+        }
+    }
+}
+
+/// Distributes the rows of a grid over tiles of the specified size, row by row.
+fn split_rows<'t, T: Copy, R: TileRow<T>>(
+    rows: impl Iterator<Item = R>,
+    width: usize,
+    height: usize,
+    tile_width: usize,
+    tile_height: usize,
+    split_at: impl Fn(R, usize) -> (R, R),
+) -> Vec<TileMut<'t, T, R>> {
+    assert!(tile_width > 0 && tile_height > 0, "tiles cannot be empty");
+
+    let mut tiles = Vec::new();
+    for y in (0..height).step_by(tile_height) {
+        for x in (0..width).step_by(tile_width) {
+            tiles.push(TileMut {
+                rows: Vec::with_capacity(tile_height.min(height - y)),
+                x,
+                y,
+                width: tile_width.min(width - x),
+                phantom: PhantomData,
+            });
+        }
+    }
+
+    let columns = (width + tile_width - 1) / tile_width;
+    for (y, mut row) in rows.enumerate() {
+        let first = y / tile_height * columns;
+        for tile in &mut tiles[first..first + columns] {
+            let (head, tail) = split_at(row, tile.width);
+            tile.rows.push(head);
+            row = tail;
+        }
+    }
+    tiles
+}
+
+impl<T: Value> ValueGrid<T> {
+    /// Splits the grid into tiles of the specified size that can be modified independently.
+    ///
+    /// Tiles are returned row by row. Tiles at the right and bottom edges are smaller
+    /// if the size of the grid is not a multiple of the tile size.
+    ///
+    /// # Panics
+    ///
+    /// - when `tile_width` or `tile_height` are zero
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use servicepoint::*;
+    /// let mut grid = CharGrid::new(TILE_WIDTH, TILE_HEIGHT);
+    /// std::thread::scope(|scope| {
+    ///     for mut tile in grid.split_into_tiles_mut(TILE_WIDTH / 2, TILE_HEIGHT / 2) {
+    ///         scope.spawn(move || {
+    ///             let (x, _) = tile.position();
+    ///             tile.fill(if x == 0 { '#' } else { '.' });
+    ///         });
+    ///     }
+    /// });
+    /// assert_eq!(grid.get(0, 0), '#');
+    /// assert_eq!(grid.get(TILE_WIDTH - 1, 0), '.');
+    /// ```
+    pub fn split_into_tiles_mut(
+        &mut self,
+        tile_width: usize,
+        tile_height: usize,
+    ) -> Vec<TileMut<'_, T>> {
+        let (width, height) = (self.width(), self.height());
+        // an empty grid has no rows, but chunks cannot be empty
+        split_rows(
+            self.data_ref_mut().chunks_exact_mut(width.max(1)),
+            width,
+            height,
+            tile_width,
+            tile_height,
+            <[T]>::split_at_mut,
+        )
+    }
+}
+
+impl Bitmap {
+    /// Splits the bitmap into tiles of the specified size that can be modified independently.
+    ///
+    /// Tiles are returned row by row. Tiles at the right and bottom edges are smaller
+    /// if the size of the bitmap is not a multiple of the tile size.
+    ///
+    /// The tile size does not need to be a multiple of 8.
+    /// When tiles share a byte, it is accessed atomically.
+    ///
+    /// # Panics
+    ///
+    /// - when `tile_width` or `tile_height` are zero
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use servicepoint::*;
+    /// let mut bitmap = Bitmap::max_sized();
+    /// std::thread::scope(|scope| {
+    ///     for mut tile in bitmap.split_into_tiles_mut(PIXEL_WIDTH / 4, PIXEL_HEIGHT) {
+    ///         scope.spawn(move || {
+    ///             for y in (0..tile.height()).step_by(2) {
+    ///                 for x in 0..tile.width() {
+    ///                     tile.set(x, y, true);
+    ///                 }
+    ///             }
+    ///         });
+    ///     }
+    /// });
+    /// assert_eq!(bitmap.count_ones(), PIXEL_COUNT / 2);
+    /// ```
+    pub fn split_into_tiles_mut(
+        &mut self,
+        tile_width: usize,
+        tile_height: usize,
+    ) -> Vec<BitmapTileMut<'_>> {
+        let (width, height) = (self.width(), self.height());
+        // an empty grid has no rows, but chunks cannot be empty
+        split_rows(
+            self.bits_mut().chunks_exact_mut(width.max(1)),
+            width,
+            height,
+            tile_width,
+            tile_height,
+            BitSlice::split_at_mut,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Bitmap, ByteGrid, DataRef, Grid, GridMut};
+
+    #[test]
+    fn split_value_grid() {
+        let mut grid = ByteGrid::new(5, 3);
+        let tiles = grid.split_into_tiles_mut(2, 2);
+        let sizes: Vec<_> = tiles
+            .iter()
+            .map(|tile| (tile.position(), tile.width(), tile.height()))
+            .collect();
+        assert_eq!(
+            sizes,
+            [
+                ((0, 0), 2, 2),
+                ((2, 0), 2, 2),
+                ((4, 0), 1, 2),
+                ((0, 2), 2, 1),
+                ((2, 2), 2, 1),
+                ((4, 2), 1, 1),
+            ]
+        );
+
+        std::thread::scope(|scope| {
+            for (index, mut tile) in tiles.into_iter().enumerate() {
+                scope.spawn(move || tile.fill(index as u8));
+            }
+        });
+        #[rustfmt::skip]
+        assert_eq!(grid.data_ref(), [
+            0, 0, 1, 1, 2,
+            0, 0, 1, 1, 2,
+            3, 3, 4, 4, 5,
+        ]);
+    }
+
+    #[test]
+    fn split_bitmap() {
+        let mut bitmap = Bitmap::new(16, 2).unwrap();
+        let mut tiles = bitmap.split_into_tiles_mut(3, 2);
+        assert_eq!(tiles.len(), 6);
+        assert_eq!(tiles[5].width(), 1);
+        assert!(!tiles[0].set_optional(3, 0, true));
+        assert!(tiles[1].get_optional(0, 2).is_none());
+
+        std::thread::scope(|scope| {
+            for mut tile in tiles.into_iter().skip(1).step_by(2) {
+                scope.spawn(move || tile.fill(true));
+            }
+        });
+        assert_eq!(
+            bitmap.data_ref(),
+            [0b0001_1100, 0b0111_0001, 0b0001_1100, 0b0111_0001]
+        );
+    }
+
+    #[test]
+    fn split_empty() {
+        assert!(ByteGrid::new(0, 4).split_into_tiles_mut(1, 1).is_empty());
+        assert!(Bitmap::new(8, 0)
+            .unwrap()
+            .split_into_tiles_mut(1, 1)
+            .is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_zero_size() {
+        ByteGrid::new(1, 1).split_into_tiles_mut(0, 1);
+    }
+}
@@ -3,112 +3,100 @@ use crate::{
     Grid, GridMut,
 };
 use std::{
+    fmt::{Debug, Formatter},
     marker::PhantomData,
     ops::{Range, RangeBounds},
+    ptr::NonNull,
+    sync::{Arc, Mutex, PoisonError},
 };
 
-macro_rules! define_window {
-    ($name:ident, $grid:ty) => {
-        /// A window into a 2D grid.
-        ///
-        /// All operations are done directly on the contained reference,
-        /// but translated to where the window is.
-        #[derive(Debug)]
-        pub struct $name<'t, TElement: Copy, TGrid: Grid<TElement>> {
-            grid: $grid,
-            xs: Range<usize>,
-            ys: Range<usize>,
-            phantom: PhantomData<TElement>,
+/// The grid behind a window, shared with the other windows created from the same
+/// [`Window::new`] or [`WindowMut::new`] call.
+///
+/// Windows never keep a reference to the grid.
+/// Instead, the grid is only borrowed for the duration of each operation,
+/// so windows into disjoint parts of the same grid can be used in any order.
+struct SharedGrid<TGrid> {
+    /// Points to a grid that is borrowed for the lifetime of the window,
+    /// mutably if the window was created from a [`WindowMut`].
+    grid: NonNull<TGrid>,
+    /// Serializes the operations of windows that may be used from different threads.
+    ///
+    /// Present once a [`WindowMut`] has been split,
+    /// before that only one window can write to the grid and no window can read while it does.
+    lock: Option<Arc<Mutex<()>>>,
+}
+
+impl<TGrid> SharedGrid<TGrid> {
+    fn new(grid: NonNull<TGrid>) -> Self {
+        Self { grid, lock: None }
+    }
+
+    /// Access for another window, which can only be used while this one is borrowed.
+    fn share(&self) -> Self {
+        Self {
+            grid: self.grid,
+            lock: self.lock.clone(),
         }
+    }
 
-        impl<'t, TElement: Copy, TGrid: Grid<TElement>>
-            $name<'t, TElement, TGrid>
-        {
-            /// Create a new window into `grid`.
-            #[must_use]
-            pub fn new(
-                grid: $grid,
-                xs: impl RangeBounds<usize>,
-                ys: impl RangeBounds<usize>,
-            ) -> Option<Self> {
-                let xs = absolute_bounds_to_abs_range(xs, grid.width())?;
-                let ys = absolute_bounds_to_abs_range(ys, grid.height())?;
-                Some(Self {
-                    grid,
-                    xs,
-                    ys,
-                    phantom: PhantomData::default(),
-                })
-            }
+    /// Access for windows split from this one, which can be used independently of each other.
+    fn split(&mut self) -> Self {
+        let lock = self.lock.get_or_insert_with(Arc::default);
+        Self {
+            grid: self.grid,
+            lock: Some(Arc::clone(lock)),
+        }
+    }
 
-            #[must_use]
-            /// Creates a window into the window.
-            ///
-            /// Returns None in case the window does not fit.
-            pub fn window(
-                &self,
-                xs: impl RangeBounds<usize>,
-                ys: impl RangeBounds<usize>,
-            ) -> Option<Window<'_, TElement, TGrid>> {
-                let xs = relative_bounds_to_abs_range(xs, self.xs.clone())?;
-                let ys = relative_bounds_to_abs_range(ys, self.ys.clone())?;
-                Window::new(self.grid, xs, ys)
+    /// Borrows the grid for the duration of `f`.
+    fn read<R>(&self, f: impl FnOnce(&TGrid) -> R) -> R {
+        let _guard = self
+            .lock
+            .as_ref()
+            .map(|lock| lock.lock().unwrap_or_else(PoisonError::into_inner));
+        // SAFETY: the grid is borrowed for the lifetime of the window, so the pointer is valid.
+        // Windows that can write to the grid at the same time share the lock,
+        // and no reference to the grid outlives the operation holding it.
+        f(unsafe { self.grid.as_ref() })
+    }
+
+    /// Borrows the grid mutably for the duration of `f`.
+    ///
+    /// Only used by [`WindowMut`], for which the grid is borrowed mutably.
+    fn write<R>(&mut self, f: impl FnOnce(&mut TGrid) -> R) -> R {
+        let _guard = self
+            .lock
+            .as_ref()
+            .map(|lock| lock.lock().unwrap_or_else(PoisonError::into_inner));
+        // SAFETY: the grid is borrowed mutably for the lifetime of the window, so the pointer is valid.
+        // Other windows into the same grid only reference it during their own operations,
+        // which are either excluded by the borrow of self or by the shared lock.
+        f(unsafe { self.grid.as_mut() })
+    }
+}
+
+macro_rules! define_window {
+    ($name:ident) => {
+        impl<TElement: Copy, TGrid: Grid<TElement>> $name<'_, TElement, TGrid> {
+            /// The columns of the underlying grid covered by this window.
+            pub(crate) fn xs(&self) -> Range<usize> {
+                self.xs.clone()
             }
 
-            /// Splits the window horizontally, returning windows to the left and right parts.
-            ///
-            /// The right window fills the remaining width, which may be zero.
-            ///
-            /// Returns None for out-of-bounds.
-            #[must_use]
-            pub fn split_horizontal(
-                &'t self,
-                left_width: usize,
-            ) -> Option<(
-                Window<'t, TElement, TGrid>,
-                Window<'t, TElement, TGrid>,
-            )> {
-                assert!(left_width <= self.width());
-                let middle_abs = self.xs.start + left_width;
-                let left = Window::new(
-                    self.grid,
-                    self.xs.start..middle_abs,
-                    self.ys.clone(),
-                )?;
-                let right = Window::new(
-                    self.grid,
-                    middle_abs..self.xs.end,
-                    self.ys.clone(),
-                )?;
-                Some((left, right))
+            /// The rows of the underlying grid covered by this window.
+            pub(crate) fn ys(&self) -> Range<usize> {
+                self.ys.clone()
             }
 
-            /// Splits the window vertically, returning windows to the top and bottom parts.
+            /// Borrows the grid this window points into for the duration of `f`.
             ///
-            /// The bottom window fills the remaining height, which may be zero.
-            ///
-            /// Returns None for out-of-bounds.
-            #[must_use]
-            pub fn split_vertical(
-                &'t self,
-                top_height: usize,
-            ) -> Option<(
-                Window<'t, TElement, TGrid>,
-                Window<'t, TElement, TGrid>,
-            )> {
-                assert!(top_height <= self.height());
-                let middle_abs = self.ys.start + top_height;
-                let top = Window::new(
-                    self.grid,
-                    self.xs.clone(),
-                    self.ys.start..middle_abs,
-                )?;
-                let bottom = Window::new(
-                    self.grid,
-                    self.xs.clone(),
-                    middle_abs..self.ys.end,
-                )?;
-                Some((top, bottom))
+            /// `f` must not use other windows into the same grid.
+            pub(crate) fn with_grid<R>(
+                &self,
+                f: impl FnOnce(&TGrid) -> R,
+            ) -> R {
+                self.grid.read(f)
             }
         }
 
@@ -117,7 +105,8 @@ macro_rules! define_window {
         {
             fn get_optional(&self, x: usize, y: usize) -> Option<TElement> {
                 if self.is_in_bounds(x, y) {
-                    Some(self.grid.get(self.xs.start + x, self.ys.start + y))
+                    let (x, y) = (self.xs.start + x, self.ys.start + y);
+                    Some(self.with_grid(|grid| grid.get(x, y)))
                 } else {
                     None
                 }
@@ -131,18 +120,259 @@ macro_rules! define_window {
                 self.ys.len()
             }
         }
+
+        impl<TElement: Copy, TGrid: Grid<TElement> + Debug> Debug
+            for $name<'_, TElement, TGrid>
+        {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                self.with_grid(|grid| {
+                    f.debug_struct(stringify!($name))
+                        .field("grid", grid)
+                        .field("xs", &self.xs)
+                        .field("ys", &self.ys)
+                        .finish()
+                })
+            }
+        }
     };
 }
 
-define_window!(Window, &'t TGrid);
-define_window!(WindowMut, &'t mut TGrid);
+/// A window into a 2D grid.
+///
+/// All operations are done directly on the grid, but translated to where the window is.
+pub struct Window<'t, TElement: Copy, TGrid: Grid<TElement>> {
+    grid: SharedGrid<TGrid>,
+    xs: Range<usize>,
+    ys: Range<usize>,
+    phantom: PhantomData<(&'t TGrid, TElement)>,
+}
+
+impl<'t, TElement: Copy, TGrid: Grid<TElement>> Window<'t, TElement, TGrid> {
+    /// Create a new window into `grid`.
+    #[must_use]
+    pub fn new(
+        grid: &'t TGrid,
+        xs: impl RangeBounds<usize>,
+        ys: impl RangeBounds<usize>,
+    ) -> Option<Self> {
+        let xs = absolute_bounds_to_abs_range(xs, grid.width())?;
+        let ys = absolute_bounds_to_abs_range(ys, grid.height())?;
+        Some(Self::from_parts(
+            SharedGrid::new(NonNull::from(grid)),
+            xs,
+            ys,
+        ))
+    }
+
+    /// Creates a window into the same grid. The caller has to make sure the ranges are in bounds.
+    fn from_parts(
+        grid: SharedGrid<TGrid>,
+        xs: Range<usize>,
+        ys: Range<usize>,
+    ) -> Self {
+        Self {
+            grid,
+            xs,
+            ys,
+            phantom: PhantomData,
+        }
+    }
+
+    #[must_use]
+    /// Creates a window into the window.
+    ///
+    /// Returns None in case the window does not fit.
+    pub fn window(
+        &self,
+        xs: impl RangeBounds<usize>,
+        ys: impl RangeBounds<usize>,
+    ) -> Option<Window<'_, TElement, TGrid>> {
+        let xs = relative_bounds_to_abs_range(xs, self.xs())?;
+        let ys = relative_bounds_to_abs_range(ys, self.ys())?;
+        Some(Window::from_parts(self.grid.share(), xs, ys))
+    }
+
+    /// Splits the window horizontally, returning windows to the left and right parts.
+    ///
+    /// The right window fills the remaining width, which may be zero.
+    ///
+    /// Returns None for out-of-bounds.
+    #[must_use]
+    pub fn split_horizontal(
+        &'t self,
+        left_width: usize,
+    ) -> Option<(Window<'t, TElement, TGrid>, Window<'t, TElement, TGrid>)>
+    {
+        assert!(left_width <= self.width());
+        let left = self.window(..left_width, ..)?;
+        let right = self.window(left_width.., ..)?;
+        Some((left, right))
+    }
+
+    /// Splits the window vertically, returning windows to the top and bottom parts.
+    ///
+    /// The bottom window fills the remaining height, which may be zero.
+    ///
+    /// Returns None for out-of-bounds.
+    #[must_use]
+    pub fn split_vertical(
+        &'t self,
+        top_height: usize,
+    ) -> Option<(Window<'t, TElement, TGrid>, Window<'t, TElement, TGrid>)>
+    {
+        assert!(top_height <= self.height());
+        let top = self.window(.., ..top_height)?;
+        let bottom = self.window(.., top_height..)?;
+        Some((top, bottom))
+    }
+}
+
+/// A mutable window into a 2D grid.
+///
+/// All operations are done directly on the grid, but translated to where the window is.
+///
+/// Splitting a window results in multiple windows into the same grid that never overlap.
+/// For this to be sound, a window does not hold a reference to the grid.
+/// Instead, the grid is only borrowed for the duration of each operation.
+///
+/// Split windows can be sent to other threads, but their operations are done one after another.
+/// To work on parts of a grid in parallel, use [`crate::ValueGrid::split_into_tiles_mut`]
+/// or [`crate::Bitmap::split_into_tiles_mut`].
+pub struct WindowMut<'t, TElement: Copy, TGrid: Grid<TElement>> {
+    grid: SharedGrid<TGrid>,
+    xs: Range<usize>,
+    ys: Range<usize>,
+    phantom: PhantomData<(&'t mut TGrid, TElement)>,
+}
+
+impl<'t, TElement: Copy, TGrid: Grid<TElement>> WindowMut<'t, TElement, TGrid> {
+    /// Create a new mutable window into `grid`.
+    #[must_use]
+    pub fn new(
+        grid: &'t mut TGrid,
+        xs: impl RangeBounds<usize>,
+        ys: impl RangeBounds<usize>,
+    ) -> Option<Self> {
+        let xs = absolute_bounds_to_abs_range(xs, grid.width())?;
+        let ys = absolute_bounds_to_abs_range(ys, grid.height())?;
+        Some(Self::from_parts(
+            SharedGrid::new(NonNull::from(grid)),
+            xs,
+            ys,
+        ))
+    }
+
+    /// Creates a window into the same grid. The caller has to make sure the ranges are in bounds.
+    fn from_parts(
+        grid: SharedGrid<TGrid>,
+        xs: Range<usize>,
+        ys: Range<usize>,
+    ) -> Self {
+        Self {
+            grid,
+            xs,
+            ys,
+            phantom: PhantomData,
+        }
+    }
+
+    #[must_use]
+    /// Creates a window into the window.
+    ///
+    /// Returns None in case the window does not fit.
+    pub fn window(
+        &self,
+        xs: impl RangeBounds<usize>,
+        ys: impl RangeBounds<usize>,
+    ) -> Option<Window<'_, TElement, TGrid>> {
+        let xs = relative_bounds_to_abs_range(xs, self.xs())?;
+        let ys = relative_bounds_to_abs_range(ys, self.ys())?;
+        Some(Window::from_parts(self.grid.share(), xs, ys))
+    }
+
+    /// Splits the window horizontally, returning windows to the left and right parts.
+    ///
+    /// The right window fills the remaining width, which may be zero.
+    ///
+    /// Returns None for out-of-bounds.
+    #[must_use]
+    pub fn split_horizontal(
+        &self,
+        left_width: usize,
+    ) -> Option<(Window<'_, TElement, TGrid>, Window<'_, TElement, TGrid>)>
+    {
+        assert!(left_width <= self.width());
+        let left = self.window(..left_width, ..)?;
+        let right = self.window(left_width.., ..)?;
+        Some((left, right))
+    }
+
+    /// Splits the window vertically, returning windows to the top and bottom parts.
+    ///
+    /// The bottom window fills the remaining height, which may be zero.
+    ///
+    /// Returns None for out-of-bounds.
+    #[must_use]
+    pub fn split_vertical(
+        &self,
+        top_height: usize,
+    ) -> Option<(Window<'_, TElement, TGrid>, Window<'_, TElement, TGrid>)>
+    {
+        assert!(top_height <= self.height());
+        let top = self.window(.., ..top_height)?;
+        let bottom = self.window(.., top_height..)?;
+        Some((top, bottom))
+    }
+
+    /// Borrows the grid this window points into mutably for the duration of `f`.
+    ///
+    /// `f` must not use other windows into the same grid.
+    pub(crate) fn with_grid_mut<R>(
+        &mut self,
+        f: impl FnOnce(&mut TGrid) -> R,
+    ) -> R {
+        self.grid.write(f)
+    }
+}
+
+define_window!(Window);
+define_window!(WindowMut);
+
+// SAFETY: a window only reads from the grid. If windows writing to the same grid
+// can be on other threads, all accesses are serialized by the shared lock.
+unsafe impl<TElement: Copy + Send, TGrid: Grid<TElement> + Send + Sync> Send
+    for Window<'_, TElement, TGrid>
+{
+}
+
+// SAFETY: see above, shared access only allows reading as well.
+unsafe impl<TElement: Copy + Sync, TGrid: Grid<TElement> + Send + Sync> Sync
+    for Window<'_, TElement, TGrid>
+{
+}
+
+// SAFETY: until a window is split, it is the only one that can access the grid,
+// like a `&mut TGrid`. Afterwards, all windows into the grid share a lock
+// that serializes their accesses, like a `Mutex<TGrid>`.
+unsafe impl<TElement: Copy + Send, TGrid: Grid<TElement> + Send> Send
+    for WindowMut<'_, TElement, TGrid>
+{
+}
+
+// SAFETY: shared access only allows reading,
+// which may happen on multiple threads at once unless the window has been split.
+unsafe impl<TElement: Copy + Sync, TGrid: Grid<TElement> + Send + Sync> Sync
+    for WindowMut<'_, TElement, TGrid>
+{
+}
 
 impl<TElement: Copy, TGrid: GridMut<TElement>> GridMut<TElement>
     for WindowMut<'_, TElement, TGrid>
 {
     fn set_optional(&mut self, x: usize, y: usize, value: TElement) -> bool {
         if self.is_in_bounds(x, y) {
-            self.grid.set(self.xs.start + x, self.ys.start + y, value);
+            let (x, y) = (self.xs.start + x, self.ys.start + y);
+            self.with_grid_mut(|grid| grid.set(x, y, value));
             true
         } else {
             false
@@ -150,15 +380,20 @@ impl<TElement: Copy, TGrid: GridMut<TElement>> GridMut<TElement>
     }
 
     fn fill(&mut self, value: TElement) {
-        for y in self.ys.clone() {
-            for x in self.xs.clone() {
-                self.grid.set(x, y, value);
+        let (xs, ys) = (self.xs(), self.ys());
+        self.with_grid_mut(|grid| {
+            for y in ys {
+                for x in xs.clone() {
+                    grid.set(x, y, value);
+                }
             }
-        }
+        });
     }
 }
 
-impl<TElement: Copy, TGrid: GridMut<TElement>> WindowMut<'_, TElement, TGrid> {
+impl<'t, TElement: Copy, TGrid: GridMut<TElement>>
+    WindowMut<'t, TElement, TGrid>
+{
     /// Creates a mutable window into the grid.
     ///
     /// Returns None in case the window does not fit.
@@ -167,9 +402,43 @@ impl<TElement: Copy, TGrid: GridMut<TElement>> WindowMut<'_, TElement, TGrid> {
         xs: impl RangeBounds<usize>,
         ys: impl RangeBounds<usize>,
     ) -> Option<WindowMut<'_, TElement, TGrid>> {
-        let xs = relative_bounds_to_abs_range(xs, self.xs.clone())?;
-        let ys = relative_bounds_to_abs_range(ys, self.ys.clone())?;
-        WindowMut::new(self.grid, xs, ys)
+        let xs = relative_bounds_to_abs_range(xs, self.xs())?;
+        let ys = relative_bounds_to_abs_range(ys, self.ys())?;
+        Some(WindowMut::from_parts(self.grid.share(), xs, ys))
+    }
+
+    /// Splits the window into the provided parts, which must not overlap.
+    ///
+    /// Returns None if a part is out of bounds or overlaps with another part.
+    pub(crate) fn split_disjoint(
+        mut self,
+        parts: &[(Range<usize>, Range<usize>)],
+    ) -> Option<Vec<WindowMut<'t, TElement, TGrid>>> {
+        let overlap = |a: &Range<usize>, b: &Range<usize>| {
+            a.start < b.end && b.start < a.end
+        };
+        for (index, (xs, ys)) in parts.iter().enumerate() {
+            if xs.start > xs.end || ys.start > ys.end {
+                return None;
+            }
+            let is_empty = xs.is_empty() || ys.is_empty();
+            if !is_empty
+                && parts[..index].iter().any(|(other_xs, other_ys)| {
+                    overlap(xs, other_xs) && overlap(ys, other_ys)
+                })
+            {
+                return None;
+            }
+        }
+
+        parts
+            .iter()
+            .map(|(xs, ys)| {
+                let xs = relative_bounds_to_abs_range(xs.clone(), self.xs())?;
+                let ys = relative_bounds_to_abs_range(ys.clone(), self.ys())?;
+                Some(WindowMut::from_parts(self.grid.split(), xs, ys))
+            })
+            .collect()
     }
 
     /// Splits the window horizontally, returning windows to the left and right parts.
@@ -178,21 +447,25 @@ impl<TElement: Copy, TGrid: GridMut<TElement>> WindowMut<'_, TElement, TGrid> {
     ///
     /// Returns None for out-of-bounds.
     #[must_use]
-    pub fn split_horizontal_mut<'t>(
-        &'t mut self,
+    pub fn split_horizontal_mut(
+        &mut self,
         left_width: usize,
     ) -> Option<(
-        WindowMut<'t, TElement, TGrid>,
-        WindowMut<'t, TElement, TGrid>,
+        WindowMut<'_, TElement, TGrid>,
+        WindowMut<'_, TElement, TGrid>,
     )> {
         assert!(left_width <= self.width());
-        let (grid1, grid2): (&'t mut TGrid, &'t mut TGrid) =
-            unsafe { Self::duplicate_mutable_ref(self.grid) };
         let middle_abs = self.xs.start + left_width;
-        let left =
-            WindowMut::new(grid1, self.xs.start..middle_abs, self.ys.clone())?;
-        let right =
-            WindowMut::new(grid2, middle_abs..self.xs.end, self.ys.clone())?;
+        let left = WindowMut::from_parts(
+            self.grid.split(),
+            self.xs.start..middle_abs,
+            self.ys(),
+        );
+        let right = WindowMut::from_parts(
+            self.grid.split(),
+            middle_abs..self.xs.end,
+            self.ys(),
+        );
         Some((left, right))
     }
 
@@ -202,37 +475,27 @@ impl<TElement: Copy, TGrid: GridMut<TElement>> WindowMut<'_, TElement, TGrid> {
     ///
     /// Returns None for out-of-bounds.
     #[must_use]
-    pub fn split_vertical_mut<'t>(
-        &'t mut self,
+    pub fn split_vertical_mut(
+        &mut self,
         top_height: usize,
     ) -> Option<(
-        WindowMut<'t, TElement, TGrid>,
-        WindowMut<'t, TElement, TGrid>,
+        WindowMut<'_, TElement, TGrid>,
+        WindowMut<'_, TElement, TGrid>,
     )> {
         assert!(top_height <= self.height());
-        let (grid1, grid2): (&'t mut TGrid, &'t mut TGrid) =
-            unsafe { Self::duplicate_mutable_ref(self.grid) };
         let middle_abs = self.ys.start + top_height;
-        let top = WindowMut::<'t>::new(
-            grid1,
-            self.xs.clone(),
+        let top = WindowMut::from_parts(
+            self.grid.split(),
+            self.xs(),
             self.ys.start..middle_abs,
-        )?;
-        let bottom = WindowMut::<'t>::new(
-            grid2,
-            self.xs.clone(),
+        );
+        let bottom = WindowMut::from_parts(
+            self.grid.split(),
+            self.xs(),
             middle_abs..self.ys.end,
-        )?;
+        );
         Some((top, bottom))
     }
-
-    /// SAFETY: the returned windows do not alias
-    /// Does not work if the grid uses the same memory location for multiple cells internally.
-    /// That means for e.g. a Bitmap, middle must be byte aligned or bit refs only used with the alias flag on.
-    unsafe fn duplicate_mutable_ref<T>(it: &mut T) -> (&mut T, &mut T) {
-        let mut ptr = std::ptr::NonNull::from(it);
-        unsafe { (ptr.as_mut(), ptr.as_mut()) }
-    }
 }
 
 #[cfg(test)]
@@ -281,7 +544,7 @@ mod tests {
         );
 
         // full size view works
-        _ = grid.window(0..3, 0..4).unwrap();
+        assert!(grid.window(0..3, 0..4).is_some());
 
         // zero size view works
         assert!(grid
@@ -337,6 +600,13 @@ mod tests {
 
         let w1_1 = w1.window(.., ..).unwrap();
         assert_eq!(w1_1.get(0, 0), 2);
+        // views into a mutable window keep the position in the grid
+        let view = w1.window(1..3, 2..4).unwrap();
+        assert_eq!((view.xs(), view.ys()), (2..4, 3..5));
+        let (left, right) = w1.split_horizontal(1).unwrap();
+        assert_eq!((left.xs(), right.xs()), (1..2, 2..5));
+        let (top, bottom) = w1.split_vertical(2).unwrap();
+        assert_eq!((top.ys(), bottom.ys()), (1..3, 3..6));
 
         assert!(matches!(w1.window(.., 0..=w1.height()), None));
 
@@ -401,4 +671,48 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn split_disjoint() {
+        let mut grid = ByteGrid::new(4, 2);
+        let win = grid.window_mut(.., ..).unwrap();
+        let mut parts = win
+            .split_disjoint(&[(0..1, 0..2), (1..4, 1..2), (2..2, 0..2)])
+            .unwrap();
+        parts[0].fill(1);
+        parts[1].fill(2);
+        assert_eq!(grid.data_ref(), &[1, 0, 0, 0, 1, 2, 2, 2]);
+
+        let win = grid.window_mut(.., ..).unwrap();
+        assert!(win.split_disjoint(&[(0..2, 0..2), (1..3, 1..2)]).is_none());
+        let win = grid.window_mut(.., ..).unwrap();
+        assert!(win.split_disjoint(&[(0..5, 0..1)]).is_none());
+    }
+
+    #[test]
+    fn view_while_sibling_is_written() {
+        let mut grid = ByteGrid::new(4, 1);
+        let mut win = grid.window_mut(.., ..).unwrap();
+        let (left, mut right) = win.split_horizontal_mut(2).unwrap();
+        let view = left.window(.., ..).unwrap();
+        right.fill(2);
+        assert_eq!(view.get(0, 0), 0);
+        right.set(0, 0, 3);
+        assert_eq!(view.get(1, 0), 0);
+        assert_eq!(grid.data_ref(), &[0, 0, 3, 2]);
+    }
+
+    #[test]
+    fn split_windows_on_threads() {
+        let mut grid = ByteGrid::new(2, 4);
+        let mut win = grid.window_mut(.., ..).unwrap();
+        let (mut top, mut bottom) = win.split_vertical_mut(1).unwrap();
+        let (mut middle, mut rest) = bottom.split_vertical_mut(1).unwrap();
+        std::thread::scope(|scope| {
+            scope.spawn(move || top.fill(1));
+            scope.spawn(move || middle.fill(2));
+            scope.spawn(move || rest.fill(3));
+        });
+        assert_eq!(grid.data_ref(), &[1, 1, 2, 2, 3, 3, 3, 3]);
+    }
 }
@@ -2,8 +2,9 @@
 //!
 //! See <https://en.wikipedia.org/wiki/Code_page_437#Character_set>
 
-use crate::{CharGrid, Cp437Grid};
+use crate::{CharGrid, Cp437Grid, DataRef, Grid, InvalidCharError};
 use std::collections::HashMap;
+use unicode_normalization::UnicodeNormalization;
 
 /// An array of 256 elements, mapping most of the CP437 values to UTF-8 characters
 ///
@@ -36,6 +37,8 @@ static UTF8_TO_CP437: once_cell::sync::Lazy<HashMap<char, u8>> =
         CP437_TO_UTF8
             .iter()
             .enumerate()
+            // for characters contained twice, the lower value wins
+            .rev()
             .map(
                 #[allow(clippy::cast_possible_truncation)]
                 move |(index, char)| (*char, index as u8),
@@ -66,9 +69,159 @@ pub fn str_to_cp437(utf8: &str) -> Vec<u8> {
 }
 
 /// Convert a single UTF-8 character to CP-437.
+///
+/// Characters that are not available are mapped to '?'.
 #[must_use]
 pub fn char_to_cp437(utf8: char) -> u8 {
-    *UTF8_TO_CP437.get(&utf8).unwrap_or(&MISSING_CHAR_CP437)
+    char_to_cp437_strict(utf8).unwrap_or(MISSING_CHAR_CP437)
+}
+
+/// Convert a single UTF-8 character to CP-437.
+///
+/// returns: None if the character is not available
+#[must_use]
+pub fn char_to_cp437_strict(utf8: char) -> Option<u8> {
+    UTF8_TO_CP437.get(&utf8).copied()
+}
+
+/// Convert the provided text to CP-437 bytes.
+///
+/// returns: an error for the first character that is not available
+///
+/// # Examples
+///
+/// ```rust
+/// # use servicepoint::cp437::*;
+/// assert_eq!(str_to_cp437_strict("Grüße"), Ok(b"Gr\x81\xe1e".to_vec()));
+/// assert_eq!(str_to_cp437_strict("1 €").unwrap_err().index, 2);
+/// ```
+pub fn str_to_cp437_strict(utf8: &str) -> Result<Vec<u8>, InvalidCharError> {
+    utf8.chars()
+        .enumerate()
+        .map(|(index, char)| {
+            char_to_cp437_strict(char).ok_or(InvalidCharError { index, char })
+        })
+        .collect()
+}
+
+/// Characters that are not part of CP-437, mapped to the closest text in CP-437.
+///
+/// Letters with diacritics are handled by [`transliterate_char`] without needing an entry here.
+#[rustfmt::skip]
+const TRANSLITERATIONS: &[(char, &str)] = &[
+    ('‘', "'"), ('’', "'"), ('‚', "'"), ('‛', "'"), ('′', "'"), ('‹', "<"), ('›', ">"),
+    ('“', "\""), ('”', "\""), ('„', "\""), ('‟', "\""), ('″', "\""),
+    ('‐', "-"), ('‑', "-"), ('‒', "-"), ('–', "-"), ('—', "-"), ('―', "-"), ('−', "-"),
+    ('…', "..."), ('\u{a0}', " "), ('\t', " "), ('×', "x"), ('€', "EUR"),
+    ('©', "(C)"), ('®', "(R)"), ('™', "TM"), ('‰', "%."),
+    ('ł', "l"), ('Ł', "L"), ('đ', "d"), ('Đ', "D"), ('ð', "d"), ('Ð', "D"),
+    ('ø', "o"), ('Ø', "O"), ('ı', "i"), ('ħ', "h"), ('Ħ', "H"),
+    ('œ', "oe"), ('Œ', "OE"), ('þ', "th"), ('Þ', "TH"), ('ẞ', "SS"),
+];
+
+/// Finds the closest CP-437 representation of a character.
+///
+/// Characters that are available are returned as is. Otherwise, the transliteration
+/// may consist of multiple characters, e.g. "EUR" for '€'.
+/// Letters with diacritics that are not available are reduced to their base letter.
+///
+/// returns: None if there is no close representation
+#[must_use]
+pub fn transliterate_char(utf8: char) -> Option<Vec<u8>> {
+    if let Some(cp437) = char_to_cp437_strict(utf8) {
+        return Some(vec![cp437]);
+    }
+    if let Some((_, text)) =
+        TRANSLITERATIONS.iter().find(|(char, _)| *char == utf8)
+    {
+        return Some(str_to_cp437(text));
+    }
+    // decomposes e.g. 'č' into 'c' and a combining caron
+    let base = utf8.nfd().next().filter(|base| *base != utf8)?;
+    char_to_cp437_strict(base).map(|cp437| vec![cp437])
+}
+
+/// Convert the provided text to CP-437 bytes, using the closest representation for characters
+/// that are not available.
+///
+/// Characters without a close representation are mapped to `replacement`,
+/// which is mapped to '?' itself if it is not available.
+///
+/// # Examples
+///
+/// ```rust
+/// # use servicepoint::cp437::*;
+/// let cp437 = str_to_cp437_transliterated("„Łódź“ — 5 €, 🍕", '*');
+/// assert_eq!(cp437_to_str(&cp437), "\"Lódz\" - 5 EUR, *");
+/// ```
+#[must_use]
+pub fn str_to_cp437_transliterated(utf8: &str, replacement: char) -> Vec<u8> {
+    let replacement = char_to_cp437(replacement);
+    let mut result = Vec::with_capacity(utf8.len());
+    for char in utf8.chars() {
+        match transliterate_char(char) {
+            Some(cp437) => result.extend(cp437),
+            None => result.push(replacement),
+        }
+    }
+    result
+}
+
+impl Cp437Grid {
+    /// Load a UTF-8 [&str] into a [`Cp437Grid`] of specified width.
+    ///
+    /// Wrapping works like in [`Self::load_ascii`], but all characters available in CP-437 are accepted.
+    /// Use [`str_to_cp437_transliterated`] and [`cp437_to_str`] first to replace the others.
+    ///
+    /// returns: an error for the first character that is not available
+    ///
+    /// # Panics
+    ///
+    /// - for width == 0
+    /// - on empty strings
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use servicepoint::*;
+    /// let grid = Cp437Grid::load_utf8("Grüße aus\n┌─ Berlin ─┐", 11, false).unwrap();
+    /// assert_eq!(grid.height(), 2);
+    ///
+    /// let error = Cp437Grid::load_utf8("Kosten: 5€", 11, false).unwrap_err();
+    /// assert_eq!((error.index, error.char), (9, '€'));
+    ///
+    /// # let connection = FakeConnection;
+    /// connection.send_command(Cp437GridCommand { origin: Origin::ZERO, grid }).unwrap();
+    /// ```
+    pub fn load_utf8(
+        value: &str,
+        width: usize,
+        wrap: bool,
+    ) -> Result<Self, InvalidCharError> {
+        Self::load_with(value, width, wrap, char_to_cp437_strict)
+    }
+
+    /// Converts each cell of the [`CharGrid`] to CP-437.
+    ///
+    /// Unlike the conversion with [From], no characters are replaced.
+    ///
+    /// returns: an error for the first character that is not available,
+    /// with the index counting cells row by row
+    pub fn try_from_char_grid(
+        value: &CharGrid,
+    ) -> Result<Self, InvalidCharError> {
+        let mut result = Self::new(value.width(), value.height());
+        for (index, (cell, char)) in result
+            .data_ref_mut()
+            .iter_mut()
+            .zip(value.data_ref())
+            .enumerate()
+        {
+            *cell = char_to_cp437_strict(*char)
+                .ok_or(InvalidCharError { index, char: *char })?;
+        }
+        Ok(result)
+    }
 }
 
 impl From<&Cp437Grid> for CharGrid {
@@ -134,6 +287,72 @@ mod tests_feature_cp437 {
         assert_eq!(cp437_to_char(char_to_cp437('😜')), '?');
     }
 
+    #[test]
+    fn space_is_ascii() {
+        assert_eq!(char_to_cp437(' '), b' ');
+        assert_eq!(str_to_cp437("a b"), b"a b");
+    }
+
+    #[test]
+    fn strict() {
+        assert_eq!(char_to_cp437_strict('ä'), Some(0x84));
+        assert_eq!(char_to_cp437_strict('€'), None);
+        assert_eq!(
+            str_to_cp437_strict("ab€"),
+            Err(InvalidCharError {
+                index: 2,
+                char: '€'
+            })
+        );
+
+        let grid = CharGrid::from("ab\ncé");
+        let cp437 = Cp437Grid::try_from_char_grid(&grid).unwrap();
+        assert_eq!(cp437.data_ref(), b"abc\x82");
+        let grid = CharGrid::from("ab\ncč");
+        assert_eq!(
+            Cp437Grid::try_from_char_grid(&grid),
+            Err(InvalidCharError {
+                index: 3,
+                char: 'č'
+            })
+        );
+    }
+
+    #[test]
+    fn load_utf8() {
+        let grid = Cp437Grid::load_utf8("äöü\nß°", 2, true).unwrap();
+        assert_eq!(grid.height(), 3);
+        assert_eq!(grid.data_ref(), [0x84, 0x94, 0x81, 0, 0xE1, 0xF8]);
+
+        let grid = Cp437Grid::load_utf8("äöü\nß°", 2, false).unwrap();
+        assert_eq!(grid.data_ref(), [0x84, 0x94, 0xE1, 0xF8]);
+
+        assert_eq!(
+            Cp437Grid::load_utf8("ok\n€", 2, false),
+            Err(InvalidCharError {
+                index: 3,
+                char: '€'
+            })
+        );
+    }
+
+    #[test]
+    fn transliterate() {
+        assert_eq!(transliterate_char('ä'), Some(vec![0x84]));
+        assert_eq!(transliterate_char('„'), Some(b"\"".to_vec()));
+        assert_eq!(transliterate_char('ł'), Some(b"l".to_vec()));
+        assert_eq!(transliterate_char('€'), Some(b"EUR".to_vec()));
+        assert_eq!(transliterate_char('Č'), Some(b"C".to_vec()));
+        assert_eq!(transliterate_char('😜'), None);
+
+        assert_eq!(
+            str_to_cp437_transliterated("naïve – déjà vu…", '?'),
+            b"na\x8bve - d\x82j\x85 vu..."
+        );
+        assert_eq!(str_to_cp437_transliterated("😜", '#'), b"#");
+        assert_eq!(str_to_cp437_transliterated("😜", '😜'), b"?");
+    }
+
     #[test]
     fn round_trip_cp437() {
         let utf8 = CharGrid::load(2, 2, &['Ä', 'x', '\n', '$']).unwrap();
@@ -0,0 +1,487 @@
+use crate::{
+    origin::DisplayUnit, Bitmap, DataRef, Grid, GridMut, Origin, Pixels,
+    Region, Tiles, Value, ValueGrid, TILE_SIZE,
+};
+use std::{marker::PhantomData, ops::Range};
+
+/// The cells that differ between two grids of the same size.
+///
+/// The unit specifies what a cell is, e.g. a pixel of a [Bitmap] or a tile of a [`crate::CharGrid`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use servicepoint::*;
+/// # let connection = FakeConnection;
+/// let old = Bitmap::max_sized();
+/// let mut new = old.clone();
+/// new.set(10, 3, true);
+/// new.set(11, 4, true);
+///
+/// let diff = GridDiff::bitmaps(&old, &new).unwrap();
+/// assert_eq!(diff.cells().count(), 2);
+/// for region in diff.tile_regions(0) {
+///     let bitmap = new.extract(region.xs(), region.ys()).unwrap();
+///     connection.send_command(BitmapCommand {
+///         bitmap,
+///         origin: region.origin,
+///         compression: CompressionCode::default(),
+///     }).unwrap();
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridDiff<Unit: DisplayUnit> {
+    changes: ValueGrid<bool>,
+    phantom: PhantomData<Unit>,
+}
+
+impl<Unit: DisplayUnit> GridDiff<Unit> {
+    /// A diff of the specified size without any changes.
+    pub(crate) fn unchanged(width: usize, height: usize) -> Self {
+        Self {
+            changes: ValueGrid::new(width, height),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Compares two grids cell by cell.
+    ///
+    /// returns: None if the grids are not of the same size
+    #[must_use]
+    pub fn new<T: PartialEq>(
+        old: &impl Grid<T>,
+        new: &impl Grid<T>,
+    ) -> Option<Self> {
+        if old.width() != new.width() || old.height() != new.height() {
+            return None;
+        }
+        let mut result = Self::unchanged(new.width(), new.height());
+        for y in 0..new.height() {
+            for x in 0..new.width() {
+                if old.get(x, y) != new.get(x, y) {
+                    result.mark(x, y);
+                }
+            }
+        }
+        Some(result)
+    }
+
+    /// Like [`Self::new`], but skips rows that did not change as a whole,
+    /// e.g. for a [`crate::ByteGrid`] or [`crate::CharGrid`].
+    #[must_use]
+    pub fn value_grids<T: Value + PartialEq>(
+        old: &ValueGrid<T>,
+        new: &ValueGrid<T>,
+    ) -> Option<Self> {
+        if old.width() != new.width() || old.height() != new.height() {
+            return None;
+        }
+        let mut result = Self::unchanged(new.width(), new.height());
+        for (y, (old_row, new_row)) in
+            old.iter_rows().zip(new.iter_rows()).enumerate()
+        {
+            let (old_row, new_row) = (old_row.as_slice(), new_row.as_slice());
+            if old_row == new_row {
+                continue;
+            }
+            for (x, (old, new)) in old_row.iter().zip(new_row).enumerate() {
+                if old != new {
+                    result.mark(x, y);
+                }
+            }
+        }
+        Some(result)
+    }
+
+    /// The width of the compared grids.
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.changes.width()
+    }
+
+    /// The height of the compared grids.
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.changes.height()
+    }
+
+    /// Checks whether there are no changes at all.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        !self.changes.iter().any(|changed| *changed)
+    }
+
+    /// Checks whether the cell at the specified position changed.
+    ///
+    /// # Panics
+    ///
+    /// When accessing `x` or `y` out of bounds.
+    #[must_use]
+    pub fn is_changed(&self, x: usize, y: usize) -> bool {
+        self.changes.get(x, y)
+    }
+
+    /// Marks the cell at the specified position as changed.
+    pub(crate) fn mark(&mut self, x: usize, y: usize) {
+        self.changes.set(x, y, true);
+    }
+
+    /// Marks all cells as changed.
+    pub(crate) fn mark_all(&mut self) {
+        self.changes.fill(true);
+    }
+
+    /// Iterates over all changed cells, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = Origin<Unit>> + '_ {
+        self.changes
+            .enumerate()
+            .filter(|(_, _, changed)| *changed)
+            .map(|(x, y, _)| Origin::new(x, y))
+    }
+
+    /// Iterates over the indices of all rows containing at least one changed cell.
+    pub fn rows(&self) -> impl Iterator<Item = usize> + '_ {
+        self.changes
+            .iter_rows()
+            .enumerate()
+            .filter_map(|(y, mut row)| row.any(|changed| *changed).then_some(y))
+    }
+
+    /// Merges the changed cells into rectangles covering all of them.
+    ///
+    /// Changes are merged into the same rectangle when they are at most `tolerance`
+    /// unchanged cells apart in both directions.
+    /// Higher values result in fewer, but larger regions.
+    ///
+    /// The rectangles do not overlap and are ordered by their top left corner, row by row.
+    #[must_use]
+    pub fn regions(&self, tolerance: usize) -> Vec<Region<Unit>> {
+        let mut active: Vec<Region<Unit>> = Vec::new();
+        let mut done = Vec::new();
+        for y in 0..self.height() {
+            // regions too far above the current row cannot grow anymore
+            let (far, near) = active
+                .into_iter()
+                .partition(|region| region.ys().end + tolerance < y);
+            done.extend::<Vec<_>>(far);
+            active = near;
+
+            for run in self.runs(y, tolerance) {
+                insert_merged(&mut active, run, tolerance);
+            }
+        }
+
+        // regions that grew sideways may have gotten close to earlier ones
+        let mut result = Vec::with_capacity(done.len() + active.len());
+        for region in done.into_iter().chain(active) {
+            insert_merged(&mut result, region, tolerance);
+        }
+        result.sort_by_key(|region| (region.origin.y, region.origin.x));
+        result
+    }
+
+    /// Rectangles covering exactly the changed cells.
+    ///
+    /// Unlike [`Self::regions`], unchanged cells are never included,
+    /// which is needed when sending them would overwrite something else on the display.
+    /// Changed cells next to each other in a row are merged.
+    /// Those runs are merged with the rows above if they span the same columns.
+    #[must_use]
+    pub fn exact_regions(&self) -> Vec<Region<Unit>> {
+        let mut regions: Vec<Region<Unit>> = Vec::new();
+        for y in 0..self.height() {
+            for run in self.runs(y, 0) {
+                let above = regions.iter_mut().find(|region| {
+                    region.origin.x == run.origin.x
+                        && region.width == run.width
+                        && region.ys().end == y
+                });
+                match above {
+                    Some(region) => region.height += 1,
+                    None => regions.push(run),
+                }
+            }
+        }
+        regions
+    }
+
+    /// The changed cells in row `y`, merged when at most `tolerance` cells apart.
+    fn runs(
+        &self,
+        y: usize,
+        tolerance: usize,
+    ) -> impl Iterator<Item = Region<Unit>> + '_ {
+        let mut x = 0;
+        std::iter::from_fn(move || {
+            let start = (x..self.width()).find(|x| self.changes.get(*x, y))?;
+            let mut end = start + 1;
+            while let Some(next) = (end..self.width())
+                .take(tolerance + 1)
+                .find(|x| self.changes.get(*x, y))
+            {
+                end = next + 1;
+            }
+            x = end;
+            Some(Region::new(Origin::new(start, y), end - start, 1))
+        })
+    }
+}
+
+/// Checks whether at most `tolerance` cells are between the regions in both directions.
+fn is_near<Unit: DisplayUnit>(
+    a: &Region<Unit>,
+    b: &Region<Unit>,
+    tolerance: usize,
+) -> bool {
+    let gap = |a: Range<usize>, b: Range<usize>| {
+        b.start
+            .saturating_sub(a.end)
+            .max(a.start.saturating_sub(b.end))
+    };
+    gap(a.xs(), b.xs()) <= tolerance && gap(a.ys(), b.ys()) <= tolerance
+}
+
+/// Adds `region` to `regions`, merging it with all regions near it.
+///
+/// If no two regions were near each other before, this is still the case afterward.
+fn insert_merged<Unit: DisplayUnit>(
+    regions: &mut Vec<Region<Unit>>,
+    mut region: Region<Unit>,
+    tolerance: usize,
+) {
+    while let Some(index) = regions
+        .iter()
+        .position(|other| is_near(other, &region, tolerance))
+    {
+        region = region.union(regions.swap_remove(index));
+    }
+    regions.push(region);
+}
+
+impl GridDiff<Pixels> {
+    /// Compares two bitmaps pixel by pixel.
+    ///
+    /// Unlike [`Self::new`], this compares whole bytes and skips rows that did not change.
+    ///
+    /// returns: None if the bitmaps are not of the same size
+    #[must_use]
+    pub fn bitmaps(old: &Bitmap, new: &Bitmap) -> Option<Self> {
+        if old.width() != new.width() || old.height() != new.height() {
+            return None;
+        }
+        let mut result = Self::unchanged(new.width(), new.height());
+        // an empty bitmap has no rows, but chunks cannot be empty
+        let row_bytes = (new.width() / TILE_SIZE).max(1);
+        let rows = old
+            .data_ref()
+            .chunks_exact(row_bytes)
+            .zip(new.data_ref().chunks_exact(row_bytes));
+        for (y, (old_row, new_row)) in rows.enumerate() {
+            if old_row == new_row {
+                continue;
+            }
+            for (column, (old, new)) in old_row.iter().zip(new_row).enumerate()
+            {
+                let changed = old ^ new;
+                for bit in 0..TILE_SIZE {
+                    if changed & (0x80 >> bit) != 0 {
+                        result.mark(column * TILE_SIZE + bit, y);
+                    }
+                }
+            }
+        }
+        Some(result)
+    }
+
+    /// Reduces the changes to tiles. A tile changed if any of its pixels changed.
+    ///
+    /// Tiles at the right and bottom edges may be partially outside of the grid.
+    #[must_use]
+    pub fn to_tiles(&self) -> GridDiff<Tiles> {
+        let width = (self.width() + TILE_SIZE - 1) / TILE_SIZE;
+        let height = (self.height() + TILE_SIZE - 1) / TILE_SIZE;
+        let mut result = GridDiff::unchanged(width, height);
+        for origin in self.cells() {
+            result.mark(origin.x / TILE_SIZE, origin.y / TILE_SIZE);
+        }
+        result
+    }
+
+    /// Like [`Self::regions`], but with regions starting and ending on tile boundaries
+    /// (or the edges of the grid), so each of them can be sent as a [`crate::BitmapCommand`].
+    ///
+    /// `tolerance` is measured in tiles.
+    #[must_use]
+    pub fn tile_regions(&self, tolerance: usize) -> Vec<Region<Pixels>> {
+        self.to_tiles()
+            .pixel_regions(tolerance, self.width(), self.height())
+    }
+}
+
+impl GridDiff<Tiles> {
+    /// Like [`Self::regions`], but converted to pixels and clipped to a grid of the specified size.
+    pub(crate) fn pixel_regions(
+        &self,
+        tolerance: usize,
+        width: usize,
+        height: usize,
+    ) -> Vec<Region<Pixels>> {
+        clip_to_pixels(&self.regions(tolerance), width, height)
+    }
+
+    /// Like [`Self::exact_regions`], but converted to pixels and clipped to a grid of the specified size.
+    pub(crate) fn exact_pixel_regions(
+        &self,
+        width: usize,
+        height: usize,
+    ) -> Vec<Region<Pixels>> {
+        clip_to_pixels(&self.exact_regions(), width, height)
+    }
+}
+
+/// Converts tile regions to pixels, cutting off what is outside of a grid of the specified size.
+fn clip_to_pixels(
+    regions: &[Region<Tiles>],
+    width: usize,
+    height: usize,
+) -> Vec<Region<Pixels>> {
+    regions
+        .iter()
+        .map(|tiles| {
+            let mut pixels = Region::<Pixels>::from(tiles);
+            pixels.width = pixels.width.min(width - pixels.origin.x);
+            pixels.height = pixels.height.min(height - pixels.origin.y);
+            pixels
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Bitmap, ByteGrid, CharGrid, GridDiff, GridMut, Origin, Pixels, Region,
+        Tiles,
+    };
+
+    #[test]
+    fn size_mismatch() {
+        let a = ByteGrid::new(2, 2);
+        let b = ByteGrid::new(2, 3);
+        assert!(GridDiff::<Tiles>::new(&a, &b).is_none());
+        assert!(GridDiff::<Tiles>::value_grids(&a, &b).is_none());
+        let a = Bitmap::new(8, 2).unwrap();
+        let b = Bitmap::new(16, 2).unwrap();
+        assert!(GridDiff::bitmaps(&a, &b).is_none());
+    }
+
+    #[test]
+    fn cells_and_rows() {
+        let old = CharGrid::from("abc\ndef\nghi");
+        let new = CharGrid::from("abc\nxeX\nghY");
+        let diff = GridDiff::<Tiles>::value_grids(&old, &new).unwrap();
+        assert_eq!(diff, GridDiff::new(&old, &new).unwrap());
+        assert_eq!(
+            diff.cells().collect::<Vec<_>>(),
+            [Origin::new(0, 1), Origin::new(2, 1), Origin::new(2, 2)]
+        );
+        assert_eq!(diff.rows().collect::<Vec<_>>(), [1, 2]);
+        assert!(diff.is_changed(2, 2));
+        assert!(!diff.is_changed(1, 1));
+        assert!(!diff.is_empty());
+        assert!(GridDiff::<Tiles>::new(&old, &old).unwrap().is_empty());
+    }
+
+    #[test]
+    fn regions_with_tolerance() {
+        let old = ByteGrid::new(10, 4);
+        let mut new = old.clone();
+        new.set(0, 0, 1);
+        new.set(2, 0, 1);
+        new.set(3, 1, 1);
+        new.set(9, 3, 1);
+        let diff = GridDiff::<Tiles>::new(&old, &new).unwrap();
+
+        assert_eq!(
+            diff.regions(0),
+            [
+                Region::new(Origin::new(0, 0), 1, 1),
+                Region::new(Origin::new(2, 0), 2, 2),
+                Region::new(Origin::new(9, 3), 1, 1),
+            ]
+        );
+        assert_eq!(
+            diff.regions(1),
+            [
+                Region::new(Origin::new(0, 0), 4, 2),
+                Region::new(Origin::new(9, 3), 1, 1),
+            ]
+        );
+        assert_eq!(diff.regions(5), [Region::new(Origin::ZERO, 10, 4)]);
+    }
+
+    #[test]
+    fn exact_regions() {
+        let old = ByteGrid::new(4, 3);
+        let mut new = old.clone();
+        new.fill(1);
+        new.set(3, 2, 0);
+        let diff = GridDiff::<Tiles>::new(&old, &new).unwrap();
+        assert_eq!(diff.regions(0), [Region::new(Origin::ZERO, 4, 3)]);
+        assert_eq!(
+            diff.exact_regions(),
+            [
+                Region::new(Origin::ZERO, 4, 2),
+                Region::new(Origin::new(0, 2), 3, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn regions_grown_sideways() {
+        // the left region only grows towards the right one after it was completed
+        let old = ByteGrid::new(6, 5);
+        let mut new = old.clone();
+        new.set(5, 0, 1);
+        new.set(0, 0, 1);
+        new.set(0, 1, 1);
+        new.set(0, 2, 1);
+        new.set(1, 2, 1);
+        new.set(2, 2, 1);
+        new.set(3, 2, 1);
+        new.set(4, 2, 1);
+        let diff = GridDiff::<Tiles>::new(&old, &new).unwrap();
+        assert_eq!(diff.regions(0), [Region::new(Origin::ZERO, 6, 3)]);
+    }
+
+    #[test]
+    fn bitmap_tiles() {
+        let old = Bitmap::new(24, 20).unwrap();
+        let mut new = old.clone();
+        new.set(9, 1, true);
+        new.set(17, 18, true);
+        let diff = GridDiff::bitmaps(&old, &new).unwrap();
+        assert_eq!(diff, GridDiff::new(&old, &new).unwrap());
+        assert_eq!(
+            diff.cells().collect::<Vec<_>>(),
+            [Origin::new(9, 1), Origin::new(17, 18)]
+        );
+
+        let tiles = diff.to_tiles();
+        assert_eq!((tiles.width(), tiles.height()), (3, 3));
+        assert_eq!(
+            tiles.cells().collect::<Vec<_>>(),
+            [Origin::new(1, 0), Origin::new(2, 2)]
+        );
+        assert_eq!(
+            diff.tile_regions(0),
+            [
+                Region::<Pixels>::new(Origin::new(8, 0), 8, 8),
+                Region::new(Origin::new(16, 16), 8, 4),
+            ]
+        );
+        assert_eq!(
+            diff.tile_regions(1),
+            [Region::<Pixels>::new(Origin::new(8, 0), 16, 20)]
+        );
+    }
+}
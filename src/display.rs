@@ -0,0 +1,327 @@
+use crate::{
+    Bitmap, BitmapCommand, BrightnessGrid, BrightnessGridCommand, CharGrid,
+    CharGridCommand, CompressionCode, Connection, Grid, GridDiff, Packet,
+    Pixels, Region, Tiles, TILE_HEIGHT, TILE_SIZE, TILE_WIDTH,
+};
+
+/// The character that lets the pixels below show through in the text layer of a [Display].
+pub const TRANSPARENT_CHAR: char = '\0';
+
+/// The desired state of the display, kept locally.
+///
+/// Draw into the pixel, text and brightness layers as needed,
+/// then call [`Display::flush`] to send only what changed since the last flush.
+///
+/// The text layer is shown on top of the pixels.
+/// Cells containing [`TRANSPARENT_CHAR`], which all cells do initially, let the pixels show through.
+///
+/// # Examples
+///
+/// ```rust
+/// # use servicepoint::*;
+/// # let connection = FakeConnection;
+/// let mut display = Display::new();
+/// display.bitmap_mut().fill(true);
+/// display.flush(&connection).unwrap();
+///
+/// // only the changed tiles are sent
+/// display.text_mut().set_row_str(0, "Hello").unwrap();
+/// display.brightness_mut().set(0, 0, Brightness::MIN);
+/// display.flush(&connection).unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Display {
+    bitmap: Bitmap,
+    text: CharGrid,
+    brightness: BrightnessGrid,
+    sent: Option<(Bitmap, CharGrid, BrightnessGrid)>,
+    /// compression used for the bitmap commands
+    pub compression: CompressionCode,
+}
+
+/// The commands needed to bring the display up to date.
+#[derive(Debug, Default)]
+struct Updates {
+    bitmaps: Vec<BitmapCommand>,
+    text: Vec<CharGridCommand>,
+    brightness: Vec<BrightnessGridCommand>,
+}
+
+impl Display {
+    /// Creates a display with all pixels off, no text and maximum brightness.
+    ///
+    /// The first flush sends everything.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            bitmap: Bitmap::max_sized(),
+            text: CharGrid::new(TILE_WIDTH, TILE_HEIGHT),
+            brightness: BrightnessGrid::new(TILE_WIDTH, TILE_HEIGHT),
+            sent: None,
+            compression: CompressionCode::default(),
+        }
+    }
+
+    /// The pixel layer.
+    #[must_use]
+    pub fn bitmap(&self) -> &Bitmap {
+        &self.bitmap
+    }
+
+    /// The pixel layer, writable.
+    pub fn bitmap_mut(&mut self) -> &mut Bitmap {
+        &mut self.bitmap
+    }
+
+    /// The text layer, one character per tile.
+    #[must_use]
+    pub fn text(&self) -> &CharGrid {
+        &self.text
+    }
+
+    /// The text layer, writable.
+    pub fn text_mut(&mut self) -> &mut CharGrid {
+        &mut self.text
+    }
+
+    /// The brightness of each tile.
+    #[must_use]
+    pub fn brightness(&self) -> &BrightnessGrid {
+        &self.brightness
+    }
+
+    /// The brightness of each tile, writable.
+    pub fn brightness_mut(&mut self) -> &mut BrightnessGrid {
+        &mut self.brightness
+    }
+
+    /// Makes the next flush send everything, e.g. after the display has been reset.
+    pub fn invalidate(&mut self) {
+        self.sent = None;
+    }
+
+    /// Sends the commands needed to make the display show the local state.
+    ///
+    /// Pixels, text and brightness values that are unchanged since the last successful flush
+    /// are not sent again.
+    ///
+    /// returns: None if sending failed. In that case, the next flush sends everything.
+    pub fn flush(&mut self, connection: &impl Connection) -> Option<()> {
+        let updates = self.updates();
+        self.sent = None;
+
+        let packets = updates
+            .bitmaps
+            .into_iter()
+            .map(Packet::try_from)
+            .chain(updates.text.into_iter().map(Packet::try_from))
+            .chain(updates.brightness.into_iter().map(Packet::try_from));
+        for packet in packets {
+            connection.send_packet(packet.ok()?)?;
+        }
+
+        self.sent = Some((
+            self.bitmap.clone(),
+            self.text.clone(),
+            self.brightness.clone(),
+        ));
+        Some(())
+    }
+
+    fn is_transparent(&self, x: usize, y: usize) -> bool {
+        self.text
+            .get_optional(x, y)
+            .map_or(true, |char| char == TRANSPARENT_CHAR)
+    }
+
+    fn updates(&self) -> Updates {
+        let tile_width = (self.bitmap.width() + TILE_SIZE - 1) / TILE_SIZE;
+        let tile_height = (self.bitmap.height() + TILE_SIZE - 1) / TILE_SIZE;
+        let everything = |width, height| {
+            let mut diff = GridDiff::unchanged(width, height);
+            diff.mark_all();
+            diff
+        };
+
+        let sent = self.sent.as_ref();
+        let pixels_changed = sent
+            .and_then(|(bitmap, _, _)| GridDiff::bitmaps(bitmap, &self.bitmap))
+            .map_or_else(
+                || everything(tile_width, tile_height),
+                |diff| diff.to_tiles(),
+            );
+        let text_changed = sent
+            .and_then(|(_, text, _)| GridDiff::value_grids(text, &self.text))
+            .unwrap_or_else(|| {
+                everything(self.text.width(), self.text.height())
+            });
+        let brightness_changed = sent
+            .and_then(|(_, _, brightness)| {
+                GridDiff::<Tiles>::value_grids(brightness, &self.brightness)
+            })
+            .unwrap_or_else(|| {
+                everything(self.brightness.width(), self.brightness.height())
+            });
+
+        // Pixels have to be sent where they changed or the text on top of them was removed.
+        // Text covering the pixels has to be sent where it changed.
+        // Both only send exactly the needed tiles, so they never overwrite each other.
+        let mut send_pixels =
+            GridDiff::<Tiles>::unchanged(tile_width, tile_height);
+        for y in 0..tile_height {
+            for x in 0..tile_width {
+                let changed = pixels_changed.is_changed(x, y)
+                    || (x < text_changed.width()
+                        && y < text_changed.height()
+                        && text_changed.is_changed(x, y));
+                if changed && self.is_transparent(x, y) {
+                    send_pixels.mark(x, y);
+                }
+            }
+        }
+        let mut send_text =
+            GridDiff::<Tiles>::unchanged(self.text.width(), self.text.height());
+        for origin in text_changed.cells() {
+            if !self.is_transparent(origin.x, origin.y) {
+                send_text.mark(origin.x, origin.y);
+            }
+        }
+
+        let mut updates = Updates::default();
+        for region in send_pixels.exact_regions() {
+            let mut region = Region::<Pixels>::from(&region);
+            region.height =
+                region.height.min(self.bitmap.height() - region.origin.y);
+            updates.bitmaps.push(BitmapCommand {
+                #[allow(
+                    clippy::unwrap_used,
+                    reason = "regions are tile aligned and inside of the bitmap"
+                )]
+                bitmap: self.bitmap.extract(region.xs(), region.ys()).unwrap(),
+                origin: region.origin,
+                compression: self.compression,
+            });
+        }
+        for region in send_text.exact_regions() {
+            updates.text.push(CharGridCommand {
+                #[allow(
+                    clippy::unwrap_used,
+                    reason = "regions are inside of the grid"
+                )]
+                grid: self.text.extract(region.xs(), region.ys()).unwrap(),
+                origin: region.origin,
+            });
+        }
+        for region in brightness_changed.regions(0) {
+            updates.brightness.push(BrightnessGridCommand {
+                #[allow(
+                    clippy::unwrap_used,
+                    reason = "regions are inside of the grid"
+                )]
+                grid: self
+                    .brightness
+                    .extract(region.xs(), region.ys())
+                    .unwrap(),
+                origin: region.origin,
+            });
+        }
+        updates
+    }
+}
+
+impl Default for Display {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        connection::{FailingConnection, RecordingConnection},
+        Brightness, CharGridExt, CharGridMutExt, Display, FakeConnection, Grid,
+        GridMut, Origin, TILE_HEIGHT, TILE_WIDTH,
+    };
+
+    #[test]
+    fn first_flush_sends_everything() {
+        let display = Display::new();
+        let updates = display.updates();
+        assert_eq!(updates.bitmaps.len(), 1);
+        assert_eq!(updates.bitmaps[0].bitmap, *display.bitmap());
+        assert!(updates.text.is_empty());
+        assert_eq!(updates.brightness.len(), 1);
+        assert_eq!(updates.brightness[0].grid.width(), TILE_WIDTH);
+        assert_eq!(updates.brightness[0].grid.height(), TILE_HEIGHT);
+    }
+
+    #[test]
+    fn sends_only_changes() {
+        let mut display = Display::new();
+        let connection = RecordingConnection::default();
+        display.flush(&connection).unwrap();
+        assert_eq!(connection.packets().len(), 2);
+        display.flush(&connection).unwrap();
+        assert_eq!(connection.packets().len(), 2);
+
+        display.bitmap_mut().set(17, 9, true);
+        display.brightness_mut().set(3, 4, Brightness::MIN);
+        let updates = display.updates();
+        assert_eq!(updates.bitmaps.len(), 1);
+        assert_eq!(updates.bitmaps[0].origin, Origin::new(16, 8));
+        assert_eq!(updates.bitmaps[0].bitmap.width(), 8);
+        assert_eq!(updates.brightness.len(), 1);
+        assert_eq!(updates.brightness[0].origin, Origin::new(3, 4));
+
+        display.flush(&FakeConnection).unwrap();
+        let updates = display.updates();
+        assert!(updates.bitmaps.is_empty() && updates.brightness.is_empty());
+    }
+
+    #[test]
+    fn text_covers_pixels() {
+        let mut display = Display::new();
+        display.flush(&FakeConnection).unwrap();
+
+        display.text_mut().set_row_str(1, "ab").unwrap();
+        display.bitmap_mut().fill(true);
+        let updates = display.updates();
+        assert_eq!(updates.text.len(), 1);
+        assert_eq!(updates.text[0].origin, Origin::new(0, 1));
+        assert_eq!(updates.text[0].grid.get_row_str(0), Some("ab".to_owned()));
+        // the tiles below the text are not sent
+        assert_eq!(
+            updates
+                .bitmaps
+                .iter()
+                .map(|command| (command.origin, command.bitmap.width()))
+                .collect::<Vec<_>>(),
+            [
+                (Origin::new(0, 0), TILE_WIDTH * 8),
+                (Origin::new(16, 8), TILE_WIDTH * 8 - 16),
+                (Origin::new(0, 16), TILE_WIDTH * 8),
+            ]
+        );
+        display.flush(&FakeConnection).unwrap();
+
+        // removing text reveals the pixels again
+        display.text_mut().set(1, 1, '\0');
+        let updates = display.updates();
+        assert!(updates.text.is_empty());
+        assert_eq!(updates.bitmaps.len(), 1);
+        assert_eq!(updates.bitmaps[0].origin, Origin::new(8, 8));
+    }
+
+    #[test]
+    fn failed_flush_sends_everything_again() {
+        let mut display = Display::new();
+        display.flush(&FakeConnection).unwrap();
+        display.bitmap_mut().set(0, 0, true);
+        assert!(display.flush(&FailingConnection).is_none());
+        assert_eq!(display.updates().brightness.len(), 1);
+
+        display.flush(&FakeConnection).unwrap();
+        display.invalidate();
+        assert_eq!(display.updates().brightness.len(), 1);
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{Bitmap, Grid, GridMut};
+
+/// 4x4 Bayer matrix used for the spatial part of the dithering.
+#[rustfmt::skip]
+const BAYER_4X4: [[u8; 4]; 4] = [
+    [ 0,  8,  2, 10],
+    [12,  4, 14,  6],
+    [ 3, 11,  1,  9],
+    [15,  7, 13,  5],
+];
+
+/// Produces a cycling sequence of [Bitmap]s that approximate an 8-bit grayscale image.
+///
+/// The display only knows pixels that are on or off.
+/// By combining ordered (spatial) dithering with switching the pixels on and off over multiple frames (temporal dithering),
+/// each pixel is lit for a fraction of the time that matches its gray level.
+///
+/// All frames are calculated once on creation, iterating only clones them.
+/// The iterator never ends - it starts over after the last frame.
+///
+/// To get the intended effect, send one frame every [`crate::FRAME_PACING`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use servicepoint::*;
+/// # let connection = FakeConnection;
+/// let mut gradient = ByteGrid::new(PIXEL_WIDTH, PIXEL_HEIGHT);
+/// for (index, value) in gradient.iter_mut().enumerate() {
+///     *value = ((index % PIXEL_WIDTH) * 255 / (PIXEL_WIDTH - 1)) as u8;
+/// }
+///
+/// let frames = DitheredFrames::new(&gradient).unwrap();
+/// for bitmap in frames.take(8) {
+///     connection.send_command(BitmapCommand::from(bitmap)).unwrap();
+///     std::thread::sleep(FRAME_PACING);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DitheredFrames {
+    frames: Vec<Bitmap>,
+    next: usize,
+}
+
+impl DitheredFrames {
+    /// The amount of frames used by [`DitheredFrames::new`].
+    ///
+    /// Together with the 16 levels of the spatial dithering, this results in 64 perceivable gray levels.
+    pub const DEFAULT_FRAME_COUNT: usize = 4;
+
+    /// Creates the frames for the provided grayscale image using [`Self::DEFAULT_FRAME_COUNT`] frames.
+    ///
+    /// A value of 0 means off, 255 means on.
+    ///
+    /// returns: None if the width of the image is not a multiple of 8.
+    #[must_use]
+    pub fn new(image: &impl Grid<u8>) -> Option<Self> {
+        Self::with_frame_count(image, Self::DEFAULT_FRAME_COUNT)
+    }
+
+    /// Creates the frames for the provided grayscale image.
+    ///
+    /// More frames result in more perceivable gray levels, but also in more flickering.
+    ///
+    /// # Arguments
+    ///
+    /// - `image`: the grayscale values, 0 means off and 255 means on
+    /// - `frame_count`: the length of the sequence
+    ///
+    /// returns: None if the width of the image is not a multiple of 8.
+    ///
+    /// # Panics
+    ///
+    /// - when `frame_count` is 0
+    #[must_use]
+    pub fn with_frame_count(
+        image: &impl Grid<u8>,
+        frame_count: usize,
+    ) -> Option<Self> {
+        assert!(frame_count > 0);
+        let width = image.width();
+        let height = image.height();
+        let levels = frame_count * 16;
+
+        let mut frames = Vec::with_capacity(frame_count);
+        for frame in 0..frame_count {
+            let mut bitmap = Bitmap::new(width, height)?;
+            for y in 0..height {
+                for x in 0..width {
+                    let spatial = usize::from(BAYER_4X4[y % 4][x % 4]);
+                    // every pixel goes through all temporal ranks, but they are
+                    // shifted so not all pixels of a spatial rank light up at once
+                    let temporal = (frame + spatial) % frame_count;
+                    let rank = temporal * 16 + spatial;
+                    let value = usize::from(image.get(x, y));
+                    bitmap.set(x, y, value * levels > rank * 255 + 127);
+                }
+            }
+            frames.push(bitmap);
+        }
+
+        Some(Self { frames, next: 0 })
+    }
+
+    /// The amount of frames in one cycle.
+    #[must_use]
+    pub fn frame_count(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// All frames of one cycle in order.
+    #[must_use]
+    pub fn frames(&self) -> &[Bitmap] {
+        &self.frames
+    }
+
+    /// Calculates how often each pixel is lit during one cycle.
+    ///
+    /// This is the gray level the viewer perceives, in the range 0..=[`Self::frame_count`].
+    #[must_use]
+    pub fn lit_count(&self, x: usize, y: usize) -> usize {
+        self.frames.iter().filter(|frame| frame.get(x, y)).count()
+    }
+}
+
+impl Iterator for DitheredFrames {
+    type Item = Bitmap;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let frame = self.frames[self.next].clone();
+        self.next = (self.next + 1) % self.frames.len();
+        Some(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ByteGrid, DitheredFrames, Grid, GridMut};
+
+    #[test]
+    fn invalid_width() {
+        assert_eq!(DitheredFrames::new(&ByteGrid::new(7, 2)), None);
+    }
+
+    #[test]
+    fn black_and_white() {
+        let mut image = ByteGrid::new(8, 2);
+        image.set_row(1, &[255; 8]).unwrap();
+
+        let frames = DitheredFrames::new(&image).unwrap();
+        for frame in frames.frames() {
+            assert_eq!(frame.iter_rows().next().unwrap().count_ones(), 0);
+            assert_eq!(frame.iter_rows().nth(1).unwrap().count_ones(), 8);
+        }
+    }
+
+    #[test]
+    fn temporal_levels() {
+        let mut image = ByteGrid::new(8, 8);
+        image.fill(128);
+
+        let frames = DitheredFrames::with_frame_count(&image, 4).unwrap();
+        // half of the pixels are lit on average in every frame
+        for frame in frames.frames() {
+            assert_eq!(frame.iter().filter(|p| **p).count(), 32);
+        }
+        // and every pixel is lit half of the time
+        for y in 0..image.height() {
+            for x in 0..image.width() {
+                assert_eq!(frames.lit_count(x, y), 2);
+            }
+        }
+    }
+
+    #[test]
+    fn cycles() {
+        let mut image = ByteGrid::new(8, 4);
+        image.fill(100);
+
+        let mut frames = DitheredFrames::with_frame_count(&image, 3).unwrap();
+        let first = frames.next().unwrap();
+        assert_eq!(frames.nth(1).unwrap(), frames.frames()[2]);
+        assert_eq!(frames.next().unwrap(), first);
+    }
+}
@@ -0,0 +1,467 @@
+use crate::{
+    Brightness, BrightnessGrid, BrightnessGridCommand, Connection, Grid,
+    GridMut, Origin, Packet, Tiles, FRAME_PACING,
+};
+
+/// The animation a [`BrightnessEffect`] plays.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum EffectKind {
+    Fade {
+        from: BrightnessGrid,
+        to: BrightnessGrid,
+    },
+    Pulse {
+        low: Brightness,
+        high: Brightness,
+    },
+    Ripple {
+        center: Origin<Tiles>,
+        background: Brightness,
+        peak: Brightness,
+        ring_width: usize,
+    },
+}
+
+/// Animates the brightness of each tile on the client side.
+///
+/// Unlike [`crate::FadeOutCommand`], which runs on the display, every frame is sent
+/// as a [`BrightnessGridCommand`]. Send one frame every [`crate::FRAME_PACING`].
+///
+/// The effect is an iterator over the frames to send, or it can send them itself with [`Self::play`].
+/// When not looping, it ends after the last frame.
+///
+/// # Examples
+///
+/// ```rust
+/// # use servicepoint::*;
+/// # let connection = FakeConnection;
+/// let target = BrightnessGrid::radial_gradient(
+///     TILE_WIDTH,
+///     TILE_HEIGHT,
+///     Origin::new(TILE_WIDTH / 2, TILE_HEIGHT / 2),
+///     TILE_WIDTH / 2,
+///     Brightness::MAX,
+///     Brightness::MIN,
+/// );
+/// let mut effect = BrightnessEffect::fade_in(target, 20);
+/// effect.play(&connection).unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrightnessEffect {
+    kind: EffectKind,
+    width: usize,
+    height: usize,
+    frame_count: usize,
+    frame: usize,
+    finished: bool,
+    /// which tile the top left corner of the frames is sent to
+    pub origin: Origin<Tiles>,
+    /// whether to start over after the last frame
+    pub looping: bool,
+}
+
+impl BrightnessEffect {
+    fn new(
+        kind: EffectKind,
+        width: usize,
+        height: usize,
+        frame_count: usize,
+    ) -> Self {
+        Self {
+            kind,
+            width,
+            height,
+            frame_count: frame_count.max(1),
+            frame: 0,
+            finished: false,
+            origin: Origin::ZERO,
+            looping: false,
+        }
+    }
+
+    /// Fades each tile from its current brightness to the brightness in `to`.
+    ///
+    /// The last frame equals `to`.
+    ///
+    /// returns: None if the grids are not of the same size
+    #[must_use]
+    pub fn fade_to(
+        from: BrightnessGrid,
+        to: BrightnessGrid,
+        frame_count: usize,
+    ) -> Option<Self> {
+        if from.width() != to.width() || from.height() != to.height() {
+            return None;
+        }
+        let (width, height) = (to.width(), to.height());
+        Some(Self::new(
+            EffectKind::Fade { from, to },
+            width,
+            height,
+            frame_count,
+        ))
+    }
+
+    /// Fades all tiles from [`Brightness::MIN`] to the brightness in `to`.
+    #[must_use]
+    pub fn fade_in(to: BrightnessGrid, frame_count: usize) -> Self {
+        let mut from = BrightnessGrid::new(to.width(), to.height());
+        from.fill(Brightness::MIN);
+        let (width, height) = (to.width(), to.height());
+        Self::new(EffectKind::Fade { from, to }, width, height, frame_count)
+    }
+
+    /// Smoothly changes all tiles between `low` and `high` and back, starting at `low`.
+    ///
+    /// The effect loops by default, `period` is the amount of frames per cycle.
+    #[must_use]
+    pub fn pulse(
+        width: usize,
+        height: usize,
+        low: Brightness,
+        high: Brightness,
+        period: usize,
+    ) -> Self {
+        let mut effect =
+            Self::new(EffectKind::Pulse { low, high }, width, height, period);
+        effect.looping = true;
+        effect
+    }
+
+    /// A ring of `peak` brightness spreading out from `center` by one tile per frame.
+    ///
+    /// Tiles outside of the ring are shown at `background`.
+    /// The effect ends when the ring has left the area.
+    #[must_use]
+    pub fn ripple(
+        width: usize,
+        height: usize,
+        center: Origin<Tiles>,
+        background: Brightness,
+        peak: Brightness,
+        ring_width: usize,
+    ) -> Self {
+        let farthest = [(0, 0), (width, 0), (0, height), (width, height)]
+            .into_iter()
+            .map(|(x, y)| distance(center, x, y))
+            .fold(0.0, f64::max);
+        #[allow(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            reason = "distances are positive and small"
+        )]
+        let frame_count = farthest.ceil() as usize + ring_width + 1;
+        Self::new(
+            EffectKind::Ripple {
+                center,
+                background,
+                peak,
+                ring_width,
+            },
+            width,
+            height,
+            frame_count,
+        )
+    }
+
+    /// The amount of frames in one run of the effect.
+    #[must_use]
+    pub fn frame_count(&self) -> usize {
+        self.frame_count
+    }
+
+    /// Starts the effect from the first frame again.
+    pub fn reset(&mut self) {
+        self.frame = 0;
+        self.finished = false;
+    }
+
+    /// Sends the remaining frames through the connection, waiting [`FRAME_PACING`] after each of them.
+    ///
+    /// This blocks until the effect has finished, so it never returns for looping effects
+    /// unless sending fails.
+    ///
+    /// returns: None if a frame could not be sent
+    pub fn play(&mut self, connection: &impl Connection) -> Option<()> {
+        for command in self.by_ref() {
+            connection.send_packet(Packet::try_from(command).ok()?)?;
+            std::thread::sleep(FRAME_PACING);
+        }
+        Some(())
+    }
+
+    /// Renders the frame with the specified index.
+    ///
+    /// Indices past the end are clamped to the last frame.
+    #[must_use]
+    pub fn frame(&self, index: usize) -> BrightnessGrid {
+        let index = index.min(self.frame_count - 1);
+        let mut grid = BrightnessGrid::new(self.width, self.height);
+        match &self.kind {
+            EffectKind::Fade { from, to } => {
+                let t = ratio(index + 1, self.frame_count);
+                for y in 0..self.height {
+                    for x in 0..self.width {
+                        grid.set(x, y, mix(from.get(x, y), to.get(x, y), t));
+                    }
+                }
+            }
+            EffectKind::Pulse { low, high } => {
+                let angle =
+                    std::f64::consts::TAU * ratio(index, self.frame_count);
+                grid.fill(mix(*low, *high, (1.0 - angle.cos()) / 2.0));
+            }
+            EffectKind::Ripple {
+                center,
+                background,
+                peak,
+                ring_width,
+            } => {
+                #[allow(
+                    clippy::cast_precision_loss,
+                    reason = "frame counts are way below the precision limit"
+                )]
+                let (radius, ring_width) = (index as f64, *ring_width as f64);
+                for y in 0..self.height {
+                    for x in 0..self.width {
+                        let offset = (distance(*center, x, y) - radius).abs();
+                        let t = 1.0 - offset / (ring_width + 1.0);
+                        grid.set(x, y, mix(*background, *peak, t));
+                    }
+                }
+            }
+        }
+        grid
+    }
+}
+
+impl Iterator for BrightnessEffect {
+    type Item = BrightnessGridCommand;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let grid = self.frame(self.frame);
+        self.frame += 1;
+        if self.frame >= self.frame_count {
+            self.frame = 0;
+            self.finished = !self.looping;
+        }
+        Some(BrightnessGridCommand {
+            grid,
+            origin: self.origin,
+        })
+    }
+}
+
+impl BrightnessGrid {
+    /// Creates a grid changing linearly from `from` at `start` to `to` at `end`.
+    ///
+    /// Tiles beyond either end keep the brightness of that end.
+    #[must_use]
+    pub fn linear_gradient(
+        width: usize,
+        height: usize,
+        start: Origin<Tiles>,
+        end: Origin<Tiles>,
+        from: Brightness,
+        to: Brightness,
+    ) -> Self {
+        let (start_x, start_y) = position(start.x, start.y);
+        let (end_x, end_y) = position(end.x, end.y);
+        let (dx, dy) = (end_x - start_x, end_y - start_y);
+        let length = dx * dx + dy * dy;
+
+        let mut grid = Self::new(width, height);
+        for y in 0..height {
+            for x in 0..width {
+                let (px, py) = position(x, y);
+                let t = if length == 0.0 {
+                    1.0
+                } else {
+                    ((px - start_x) * dx + (py - start_y) * dy) / length
+                };
+                grid.set(x, y, mix(from, to, t));
+            }
+        }
+        grid
+    }
+
+    /// Creates a grid changing from `inner` at `center` to `outer` at `radius` tiles away.
+    ///
+    /// Tiles farther away than `radius` are set to `outer`.
+    #[must_use]
+    pub fn radial_gradient(
+        width: usize,
+        height: usize,
+        center: Origin<Tiles>,
+        radius: usize,
+        inner: Brightness,
+        outer: Brightness,
+    ) -> Self {
+        let (radius, _) = position(radius, 0);
+        let mut grid = Self::new(width, height);
+        for y in 0..height {
+            for x in 0..width {
+                let t = if radius == 0.0 {
+                    1.0
+                } else {
+                    distance(center, x, y) / radius
+                };
+                grid.set(x, y, mix(inner, outer, t));
+            }
+        }
+        grid
+    }
+}
+
+#[allow(
+    clippy::cast_precision_loss,
+    reason = "tile positions are way below the precision limit"
+)]
+fn position(x: usize, y: usize) -> (f64, f64) {
+    (x as f64, y as f64)
+}
+
+fn distance(center: Origin<Tiles>, x: usize, y: usize) -> f64 {
+    let (center_x, center_y) = position(center.x, center.y);
+    let (x, y) = position(x, y);
+    (x - center_x).hypot(y - center_y)
+}
+
+#[allow(
+    clippy::cast_precision_loss,
+    reason = "frame counts are way below the precision limit"
+)]
+fn ratio(index: usize, count: usize) -> f64 {
+    index as f64 / count as f64
+}
+
+/// The brightness at `t` between `from` (0.0) and `to` (1.0), rounded to the nearest step.
+fn mix(from: Brightness, to: Brightness, t: f64) -> Brightness {
+    let from = f64::from(u8::from(from));
+    let to = f64::from(u8::from(to));
+    let value = from + (to - from) * t.clamp(0.0, 1.0);
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "the value is between two brightness values"
+    )]
+    Brightness::saturating_from(value.round() as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        connection::RecordingConnection, Brightness, BrightnessEffect,
+        BrightnessGrid, BrightnessGridCommand, DataRef, Grid, GridMut, Origin,
+    };
+
+    fn levels(grid: &BrightnessGrid) -> Vec<u8> {
+        grid.data_ref().iter().map(u8::from).collect()
+    }
+
+    #[test]
+    fn fade_in() {
+        let mut target = BrightnessGrid::new(2, 1);
+        target.set(1, 0, Brightness::try_from(4).unwrap());
+        let mut effect = BrightnessEffect::fade_in(target.clone(), 4);
+        effect.origin = Origin::new(3, 2);
+        let frames = effect.collect::<Vec<_>>();
+
+        assert_eq!(frames.len(), 4);
+        assert!(frames.iter().all(|frame| frame.origin == Origin::new(3, 2)));
+        assert_eq!(levels(&frames[0].grid), [3, 1]);
+        assert_eq!(levels(&frames[1].grid), [6, 2]);
+        assert_eq!(frames[3].grid, target);
+    }
+
+    #[test]
+    fn fade_to() {
+        let from = BrightnessGrid::new(2, 2);
+        let mut to = BrightnessGrid::new(2, 2);
+        to.fill(Brightness::MIN);
+        let effect =
+            BrightnessEffect::fade_to(from.clone(), to.clone(), 0).unwrap();
+        assert_eq!(effect.frame_count(), 1);
+        assert_eq!(effect.last().unwrap().grid, to);
+
+        let too_small = BrightnessGrid::new(1, 2);
+        assert!(BrightnessEffect::fade_to(from, too_small, 5).is_none());
+    }
+
+    #[test]
+    fn pulse_loops() {
+        let effect =
+            BrightnessEffect::pulse(1, 1, Brightness::MIN, Brightness::MAX, 6);
+        let frames = effect
+            .take(7)
+            .map(|command| u8::from(command.grid.get(0, 0)))
+            .collect::<Vec<_>>();
+        assert_eq!(frames, [0, 3, 8, 11, 8, 3, 0]);
+    }
+
+    #[test]
+    fn ripple_spreads() {
+        let mut effect = BrightnessEffect::ripple(
+            5,
+            1,
+            Origin::new(0, 0),
+            Brightness::MIN,
+            Brightness::MAX,
+            0,
+        );
+        assert_eq!(levels(&effect.frame(0)), [11, 0, 0, 0, 0]);
+        assert_eq!(levels(&effect.frame(2)), [0, 0, 11, 0, 0]);
+        assert_eq!(effect.frame_count(), 7);
+        assert!(effect
+            .by_ref()
+            .last()
+            .unwrap()
+            .grid
+            .data_ref()
+            .iter()
+            .all(|b| *b == Brightness::MIN));
+        assert!(effect.next().is_none());
+        effect.reset();
+        assert!(effect.next().is_some());
+    }
+
+    #[test]
+    fn gradients() {
+        let linear = BrightnessGrid::linear_gradient(
+            7,
+            1,
+            Origin::new(1, 0),
+            Origin::new(5, 0),
+            Brightness::MIN,
+            Brightness::try_from(8).unwrap(),
+        );
+        assert_eq!(levels(&linear), [0, 0, 2, 4, 6, 8, 8]);
+
+        let radial = BrightnessGrid::radial_gradient(
+            3,
+            3,
+            Origin::new(1, 1),
+            2,
+            Brightness::MAX,
+            Brightness::MIN,
+        );
+        assert_eq!(levels(&radial), [3, 6, 3, 6, 11, 6, 3, 6, 3]);
+    }
+
+    #[test]
+    fn play_sends_all_frames() {
+        let target = BrightnessGrid::new(2, 1);
+        let mut effect = BrightnessEffect::fade_in(target.clone(), 3);
+        let connection = RecordingConnection::default();
+        assert_eq!(effect.play(&connection), Some(()));
+        assert!(effect.next().is_none());
+
+        let packets = connection.packets();
+        assert_eq!(packets.len(), 3);
+        let last = BrightnessGridCommand::try_from(packets[2].clone()).unwrap();
+        assert_eq!(last.grid, target);
+    }
+}
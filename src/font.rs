@@ -0,0 +1,412 @@
+use crate::{Bitmap, DataRef, TILE_SIZE};
+
+/// The glyphs for the printable ASCII characters from `' '` to `'~'`.
+///
+/// Each byte is one row, the most significant bit is the leftmost pixel.
+/// Based on the public domain font8x8 by Daniel Hepper.
+#[rustfmt::skip]
+const GLYPHS: [[u8; 8]; 95] = [
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // space
+    [0x18, 0x3C, 0x3C, 0x18, 0x18, 0x00, 0x18, 0x00], // !
+    [0x6C, 0x6C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // "
+    [0x6C, 0x6C, 0xFE, 0x6C, 0xFE, 0x6C, 0x6C, 0x00], // #
+    [0x30, 0x7C, 0xC0, 0x78, 0x0C, 0xF8, 0x30, 0x00], // $
+    [0x00, 0xC6, 0xCC, 0x18, 0x30, 0x66, 0xC6, 0x00], // %
+    [0x38, 0x6C, 0x38, 0x76, 0xDC, 0xCC, 0x76, 0x00], // &
+    [0x60, 0x60, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00], // '
+    [0x18, 0x30, 0x60, 0x60, 0x60, 0x30, 0x18, 0x00], // (
+    [0x60, 0x30, 0x18, 0x18, 0x18, 0x30, 0x60, 0x00], // )
+    [0x00, 0x66, 0x3C, 0xFF, 0x3C, 0x66, 0x00, 0x00], // *
+    [0x00, 0x30, 0x30, 0xFC, 0x30, 0x30, 0x00, 0x00], // +
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x30, 0x60], // ,
+    [0x00, 0x00, 0x00, 0xFC, 0x00, 0x00, 0x00, 0x00], // -
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x30, 0x00], // .
+    [0x06, 0x0C, 0x18, 0x30, 0x60, 0xC0, 0x80, 0x00], // /
+    [0x7C, 0xC6, 0xCE, 0xDE, 0xF6, 0xE6, 0x7C, 0x00], // 0
+    [0x30, 0x70, 0x30, 0x30, 0x30, 0x30, 0xFC, 0x00], // 1
+    [0x78, 0xCC, 0x0C, 0x38, 0x60, 0xCC, 0xFC, 0x00], // 2
+    [0x78, 0xCC, 0x0C, 0x38, 0x0C, 0xCC, 0x78, 0x00], // 3
+    [0x1C, 0x3C, 0x6C, 0xCC, 0xFE, 0x0C, 0x1E, 0x00], // 4
+    [0xFC, 0xC0, 0xF8, 0x0C, 0x0C, 0xCC, 0x78, 0x00], // 5
+    [0x38, 0x60, 0xC0, 0xF8, 0xCC, 0xCC, 0x78, 0x00], // 6
+    [0xFC, 0xCC, 0x0C, 0x18, 0x30, 0x30, 0x30, 0x00], // 7
+    [0x78, 0xCC, 0xCC, 0x78, 0xCC, 0xCC, 0x78, 0x00], // 8
+    [0x78, 0xCC, 0xCC, 0x7C, 0x0C, 0x18, 0x70, 0x00], // 9
+    [0x00, 0x30, 0x30, 0x00, 0x00, 0x30, 0x30, 0x00], // :
+    [0x00, 0x30, 0x30, 0x00, 0x00, 0x30, 0x30, 0x60], // ;
+    [0x18, 0x30, 0x60, 0xC0, 0x60, 0x30, 0x18, 0x00], // <
+    [0x00, 0x00, 0xFC, 0x00, 0x00, 0xFC, 0x00, 0x00], // =
+    [0x60, 0x30, 0x18, 0x0C, 0x18, 0x30, 0x60, 0x00], // >
+    [0x78, 0xCC, 0x0C, 0x18, 0x30, 0x00, 0x30, 0x00], // ?
+    [0x7C, 0xC6, 0xDE, 0xDE, 0xDE, 0xC0, 0x78, 0x00], // @
+    [0x30, 0x78, 0xCC, 0xCC, 0xFC, 0xCC, 0xCC, 0x00], // A
+    [0xFC, 0x66, 0x66, 0x7C, 0x66, 0x66, 0xFC, 0x00], // B
+    [0x3C, 0x66, 0xC0, 0xC0, 0xC0, 0x66, 0x3C, 0x00], // C
+    [0xF8, 0x6C, 0x66, 0x66, 0x66, 0x6C, 0xF8, 0x00], // D
+    [0xFE, 0x62, 0x68, 0x78, 0x68, 0x62, 0xFE, 0x00], // E
+    [0xFE, 0x62, 0x68, 0x78, 0x68, 0x60, 0xF0, 0x00], // F
+    [0x3C, 0x66, 0xC0, 0xC0, 0xCE, 0x66, 0x3E, 0x00], // G
+    [0xCC, 0xCC, 0xCC, 0xFC, 0xCC, 0xCC, 0xCC, 0x00], // H
+    [0x78, 0x30, 0x30, 0x30, 0x30, 0x30, 0x78, 0x00], // I
+    [0x1E, 0x0C, 0x0C, 0x0C, 0xCC, 0xCC, 0x78, 0x00], // J
+    [0xE6, 0x66, 0x6C, 0x78, 0x6C, 0x66, 0xE6, 0x00], // K
+    [0xF0, 0x60, 0x60, 0x60, 0x62, 0x66, 0xFE, 0x00], // L
+    [0xC6, 0xEE, 0xFE, 0xFE, 0xD6, 0xC6, 0xC6, 0x00], // M
+    [0xC6, 0xE6, 0xF6, 0xDE, 0xCE, 0xC6, 0xC6, 0x00], // N
+    [0x38, 0x6C, 0xC6, 0xC6, 0xC6, 0x6C, 0x38, 0x00], // O
+    [0xFC, 0x66, 0x66, 0x7C, 0x60, 0x60, 0xF0, 0x00], // P
+    [0x78, 0xCC, 0xCC, 0xCC, 0xDC, 0x78, 0x1C, 0x00], // Q
+    [0xFC, 0x66, 0x66, 0x7C, 0x6C, 0x66, 0xE6, 0x00], // R
+    [0x78, 0xCC, 0xE0, 0x70, 0x1C, 0xCC, 0x78, 0x00], // S
+    [0xFC, 0xB4, 0x30, 0x30, 0x30, 0x30, 0x78, 0x00], // T
+    [0xCC, 0xCC, 0xCC, 0xCC, 0xCC, 0xCC, 0xFC, 0x00], // U
+    [0xCC, 0xCC, 0xCC, 0xCC, 0xCC, 0x78, 0x30, 0x00], // V
+    [0xC6, 0xC6, 0xC6, 0xD6, 0xFE, 0xEE, 0xC6, 0x00], // W
+    [0xC6, 0xC6, 0x6C, 0x38, 0x38, 0x6C, 0xC6, 0x00], // X
+    [0xCC, 0xCC, 0xCC, 0x78, 0x30, 0x30, 0x78, 0x00], // Y
+    [0xFE, 0xC6, 0x8C, 0x18, 0x32, 0x66, 0xFE, 0x00], // Z
+    [0x78, 0x60, 0x60, 0x60, 0x60, 0x60, 0x78, 0x00], // [
+    [0xC0, 0x60, 0x30, 0x18, 0x0C, 0x06, 0x02, 0x00], // \
+    [0x78, 0x18, 0x18, 0x18, 0x18, 0x18, 0x78, 0x00], // ]
+    [0x10, 0x38, 0x6C, 0xC6, 0x00, 0x00, 0x00, 0x00], // ^
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF], // _
+    [0x30, 0x30, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00], // `
+    [0x00, 0x00, 0x78, 0x0C, 0x7C, 0xCC, 0x76, 0x00], // a
+    [0xE0, 0x60, 0x60, 0x7C, 0x66, 0x66, 0xDC, 0x00], // b
+    [0x00, 0x00, 0x78, 0xCC, 0xC0, 0xCC, 0x78, 0x00], // c
+    [0x1C, 0x0C, 0x0C, 0x7C, 0xCC, 0xCC, 0x76, 0x00], // d
+    [0x00, 0x00, 0x78, 0xCC, 0xFC, 0xC0, 0x78, 0x00], // e
+    [0x38, 0x6C, 0x60, 0xF0, 0x60, 0x60, 0xF0, 0x00], // f
+    [0x00, 0x00, 0x76, 0xCC, 0xCC, 0x7C, 0x0C, 0xF8], // g
+    [0xE0, 0x60, 0x6C, 0x76, 0x66, 0x66, 0xE6, 0x00], // h
+    [0x30, 0x00, 0x70, 0x30, 0x30, 0x30, 0x78, 0x00], // i
+    [0x0C, 0x00, 0x0C, 0x0C, 0x0C, 0xCC, 0xCC, 0x78], // j
+    [0xE0, 0x60, 0x66, 0x6C, 0x78, 0x6C, 0xE6, 0x00], // k
+    [0x70, 0x30, 0x30, 0x30, 0x30, 0x30, 0x78, 0x00], // l
+    [0x00, 0x00, 0xCC, 0xFE, 0xFE, 0xD6, 0xC6, 0x00], // m
+    [0x00, 0x00, 0xF8, 0xCC, 0xCC, 0xCC, 0xCC, 0x00], // n
+    [0x00, 0x00, 0x78, 0xCC, 0xCC, 0xCC, 0x78, 0x00], // o
+    [0x00, 0x00, 0xDC, 0x66, 0x66, 0x7C, 0x60, 0xF0], // p
+    [0x00, 0x00, 0x76, 0xCC, 0xCC, 0x7C, 0x0C, 0x1E], // q
+    [0x00, 0x00, 0xDC, 0x76, 0x66, 0x60, 0xF0, 0x00], // r
+    [0x00, 0x00, 0x7C, 0xC0, 0x78, 0x0C, 0xF8, 0x00], // s
+    [0x10, 0x30, 0x7C, 0x30, 0x30, 0x34, 0x18, 0x00], // t
+    [0x00, 0x00, 0xCC, 0xCC, 0xCC, 0xCC, 0x76, 0x00], // u
+    [0x00, 0x00, 0xCC, 0xCC, 0xCC, 0x78, 0x30, 0x00], // v
+    [0x00, 0x00, 0xC6, 0xD6, 0xFE, 0xFE, 0x6C, 0x00], // w
+    [0x00, 0x00, 0xC6, 0x6C, 0x38, 0x6C, 0xC6, 0x00], // x
+    [0x00, 0x00, 0xCC, 0xCC, 0xCC, 0x7C, 0x0C, 0xF8], // y
+    [0x00, 0x00, 0xFC, 0x98, 0x30, 0x64, 0xFC, 0x00], // z
+    [0x1C, 0x30, 0x30, 0xE0, 0x30, 0x30, 0x1C, 0x00], // {
+    [0x18, 0x18, 0x18, 0x00, 0x18, 0x18, 0x18, 0x00], // |
+    [0xE0, 0x30, 0x30, 0x1C, 0x30, 0x30, 0xE0, 0x00], // }
+    [0x76, 0xDC, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // ~
+];
+
+/// The glyphs for all other characters of CP-437 and Latin-1, sorted by character.
+///
+/// Based on the public domain font8x8 by Daniel Hepper,
+/// the symbols not contained there are taken from the IBM PC 8x8 font.
+#[rustfmt::skip]
+const EXTENDED_GLYPHS: [(char, [u8; 8]); 200] = [
+    ('\u{00A0}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]), // nbsp
+    ('\u{00A1}', [0x18, 0x18, 0x00, 0x18, 0x18, 0x18, 0x18, 0x00]), // ¡
+    ('\u{00A2}', [0x18, 0x18, 0x7E, 0xC0, 0xC0, 0x7E, 0x18, 0x18]), // ¢
+    ('\u{00A3}', [0x38, 0x6C, 0x64, 0xF0, 0x60, 0xE6, 0xFC, 0x00]), // £
+    ('\u{00A4}', [0x00, 0x00, 0xC6, 0x7C, 0x6C, 0x7C, 0xC6, 0x00]), // ¤
+    ('\u{00A5}', [0xCC, 0xCC, 0x78, 0xFC, 0x30, 0xFC, 0x30, 0x30]), // ¥
+    ('\u{00A6}', [0x18, 0x18, 0x18, 0x00, 0x18, 0x18, 0x18, 0x00]), // ¦
+    ('\u{00A7}', [0x3E, 0x63, 0x38, 0x6C, 0x6C, 0x38, 0xCC, 0x78]), // §
+    ('\u{00A8}', [0xCC, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]), // ¨
+    ('\u{00A9}', [0x3C, 0x42, 0x99, 0xA1, 0xA1, 0x99, 0x42, 0x3C]), // ©
+    ('\u{00AA}', [0x3C, 0x6C, 0x6C, 0x3E, 0x00, 0x00, 0x00, 0x00]), // ª
+    ('\u{00AB}', [0x00, 0x33, 0x66, 0xCC, 0x66, 0x33, 0x00, 0x00]), // «
+    ('\u{00AC}', [0x00, 0x00, 0x00, 0xFC, 0x0C, 0x0C, 0x00, 0x00]), // ¬
+    ('\u{00AD}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]), // soft hyphen
+    ('\u{00AE}', [0x3C, 0x42, 0xB9, 0xA5, 0xB9, 0xA5, 0x42, 0x3C]), // ®
+    ('\u{00AF}', [0x7E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]), // ¯
+    ('\u{00B0}', [0x38, 0x6C, 0x6C, 0x38, 0x00, 0x00, 0x00, 0x00]), // °
+    ('\u{00B1}', [0x18, 0x18, 0x7E, 0x18, 0x18, 0x00, 0x7E, 0x00]), // ±
+    ('\u{00B2}', [0x38, 0x0C, 0x18, 0x30, 0x3C, 0x00, 0x00, 0x00]), // ²
+    ('\u{00B3}', [0x38, 0x0C, 0x18, 0x0C, 0x38, 0x00, 0x00, 0x00]), // ³
+    ('\u{00B4}', [0x18, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]), // ´
+    ('\u{00B5}', [0x00, 0x00, 0x66, 0x66, 0x66, 0x7C, 0x60, 0xC0]), // µ
+    ('\u{00B6}', [0x7F, 0xDB, 0xDB, 0x7B, 0x1B, 0x1B, 0x1B, 0x00]), // ¶
+    ('\u{00B7}', [0x00, 0x00, 0x00, 0x18, 0x18, 0x00, 0x00, 0x00]), // ·
+    ('\u{00B8}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x18, 0x0C, 0x78]), // ¸
+    ('\u{00B9}', [0x10, 0x30, 0x10, 0x38, 0x00, 0x00, 0x00, 0x00]), // ¹
+    ('\u{00BA}', [0x38, 0x6C, 0x6C, 0x38, 0x00, 0x00, 0x00, 0x00]), // º
+    ('\u{00BB}', [0x00, 0xCC, 0x66, 0x33, 0x66, 0xCC, 0x00, 0x00]), // »
+    ('\u{00BC}', [0xC3, 0xC6, 0xCC, 0xBD, 0x37, 0x6F, 0xCF, 0xC0]), // ¼
+    ('\u{00BD}', [0xC3, 0xC6, 0xCC, 0xDE, 0x33, 0x66, 0xCC, 0x0F]), // ½
+    ('\u{00BE}', [0xC0, 0x23, 0xC6, 0x2D, 0xDB, 0x35, 0x67, 0x01]), // ¾
+    ('\u{00BF}', [0x30, 0x00, 0x30, 0x60, 0xC0, 0xCC, 0x78, 0x00]), // ¿
+    ('\u{00C0}', [0xE0, 0x00, 0x38, 0x6C, 0xC6, 0xFE, 0xC6, 0x00]), // À
+    ('\u{00C1}', [0x0E, 0x00, 0x38, 0x6C, 0xC6, 0xFE, 0xC6, 0x00]), // Á
+    ('\u{00C2}', [0x38, 0x6C, 0x00, 0x7C, 0xC6, 0xFE, 0xC6, 0x00]), // Â
+    ('\u{00C3}', [0x76, 0xDC, 0x00, 0x7C, 0xC6, 0xFE, 0xC6, 0x00]), // Ã
+    ('\u{00C4}', [0xC6, 0x38, 0x6C, 0xC6, 0xFE, 0xC6, 0xC6, 0x00]), // Ä
+    ('\u{00C5}', [0x30, 0x30, 0x00, 0x78, 0xCC, 0xFC, 0xCC, 0x00]), // Å
+    ('\u{00C6}', [0x3E, 0x6C, 0xCC, 0xFE, 0xCC, 0xCC, 0xCE, 0x00]), // Æ
+    ('\u{00C7}', [0x78, 0xCC, 0xC0, 0xCC, 0x78, 0x18, 0x0C, 0x78]), // Ç
+    ('\u{00C8}', [0xE0, 0x00, 0xFC, 0x60, 0x78, 0x60, 0xFC, 0x00]), // È
+    ('\u{00C9}', [0x1C, 0x00, 0xFC, 0x60, 0x78, 0x60, 0xFC, 0x00]), // É
+    ('\u{00CA}', [0x30, 0x48, 0xFC, 0x60, 0x78, 0x60, 0xFC, 0x00]), // Ê
+    ('\u{00CB}', [0x6C, 0x00, 0xFC, 0x60, 0x78, 0x60, 0xFC, 0x00]), // Ë
+    ('\u{00CC}', [0xE0, 0x00, 0x78, 0x30, 0x30, 0x30, 0x78, 0x00]), // Ì
+    ('\u{00CD}', [0x1C, 0x00, 0x78, 0x30, 0x30, 0x30, 0x78, 0x00]), // Í
+    ('\u{00CE}', [0x30, 0x48, 0x00, 0x78, 0x30, 0x30, 0x78, 0x00]), // Î
+    ('\u{00CF}', [0xCC, 0x00, 0x78, 0x30, 0x30, 0x30, 0x78, 0x00]), // Ï
+    ('\u{00D0}', [0xFC, 0x66, 0xF6, 0xF6, 0x66, 0x66, 0xFC, 0x00]), // Ð
+    ('\u{00D1}', [0xFC, 0x00, 0xCC, 0xEC, 0xFC, 0xDC, 0xCC, 0x00]), // Ñ
+    ('\u{00D2}', [0x70, 0x00, 0x18, 0x3C, 0x66, 0x3C, 0x18, 0x00]), // Ò
+    ('\u{00D3}', [0x0E, 0x00, 0x18, 0x3C, 0x66, 0x3C, 0x18, 0x00]), // Ó
+    ('\u{00D4}', [0x3C, 0x66, 0x18, 0x3C, 0x66, 0x3C, 0x18, 0x00]), // Ô
+    ('\u{00D5}', [0x76, 0xDC, 0x00, 0x7C, 0xC6, 0xC6, 0x7C, 0x00]), // Õ
+    ('\u{00D6}', [0xC3, 0x18, 0x3C, 0x66, 0x66, 0x3C, 0x18, 0x00]), // Ö
+    ('\u{00D7}', [0x00, 0x6C, 0x38, 0x10, 0x38, 0x6C, 0x00, 0x00]), // ×
+    ('\u{00D8}', [0x3A, 0x6C, 0xCE, 0xDE, 0xF6, 0x6C, 0xB8, 0x00]), // Ø
+    ('\u{00D9}', [0x70, 0x00, 0x66, 0x66, 0x66, 0x66, 0x3C, 0x00]), // Ù
+    ('\u{00DA}', [0x0E, 0x00, 0x66, 0x66, 0x66, 0x66, 0x3C, 0x00]), // Ú
+    ('\u{00DB}', [0x3C, 0x66, 0x00, 0x66, 0x66, 0x66, 0x3C, 0x00]), // Û
+    ('\u{00DC}', [0xCC, 0x00, 0xCC, 0xCC, 0xCC, 0xCC, 0x78, 0x00]), // Ü
+    ('\u{00DD}', [0x0E, 0x00, 0x66, 0x66, 0x3C, 0x18, 0x18, 0x00]), // Ý
+    ('\u{00DE}', [0xF0, 0x60, 0x7C, 0x66, 0x66, 0x7C, 0x60, 0xF0]), // Þ
+    ('\u{00DF}', [0x00, 0x78, 0xCC, 0xF8, 0xCC, 0xF8, 0xC0, 0xC0]), // ß
+    ('\u{00E0}', [0xE0, 0x00, 0x78, 0x0C, 0x7C, 0xCC, 0x7E, 0x00]), // à
+    ('\u{00E1}', [0x1C, 0x00, 0x78, 0x0C, 0x7C, 0xCC, 0x7E, 0x00]), // á
+    ('\u{00E2}', [0x7E, 0xC3, 0x3C, 0x06, 0x3E, 0x66, 0x3F, 0x00]), // â
+    ('\u{00E3}', [0x76, 0xDC, 0x78, 0x0C, 0x7C, 0xCC, 0x7E, 0x00]), // ã
+    ('\u{00E4}', [0xCC, 0x00, 0x78, 0x0C, 0x7C, 0xCC, 0x7E, 0x00]), // ä
+    ('\u{00E5}', [0x30, 0x30, 0x78, 0x0C, 0x7C, 0xCC, 0x7E, 0x00]), // å
+    ('\u{00E6}', [0x00, 0x00, 0x7F, 0x0C, 0x7F, 0xCC, 0x7F, 0x00]), // æ
+    ('\u{00E7}', [0x00, 0x00, 0x78, 0xC0, 0xC0, 0x78, 0x0C, 0x38]), // ç
+    ('\u{00E8}', [0xE0, 0x00, 0x78, 0xCC, 0xFC, 0xC0, 0x78, 0x00]), // è
+    ('\u{00E9}', [0x1C, 0x00, 0x78, 0xCC, 0xFC, 0xC0, 0x78, 0x00]), // é
+    ('\u{00EA}', [0x7E, 0xC3, 0x3C, 0x66, 0x7E, 0x60, 0x3C, 0x00]), // ê
+    ('\u{00EB}', [0xCC, 0x00, 0x78, 0xCC, 0xFC, 0xC0, 0x78, 0x00]), // ë
+    ('\u{00EC}', [0xE0, 0x00, 0x70, 0x30, 0x30, 0x30, 0x78, 0x00]), // ì
+    ('\u{00ED}', [0x38, 0x00, 0x70, 0x30, 0x30, 0x30, 0x78, 0x00]), // í
+    ('\u{00EE}', [0x7C, 0xC6, 0x38, 0x18, 0x18, 0x18, 0x3C, 0x00]), // î
+    ('\u{00EF}', [0xCC, 0x00, 0x70, 0x30, 0x30, 0x30, 0x78, 0x00]), // ï
+    ('\u{00F0}', [0xD8, 0x70, 0xD8, 0x0C, 0x7C, 0xCC, 0x78, 0x00]), // ð
+    ('\u{00F1}', [0x00, 0xF8, 0x00, 0xF8, 0xCC, 0xCC, 0xCC, 0x00]), // ñ
+    ('\u{00F2}', [0x00, 0xE0, 0x00, 0x78, 0xCC, 0xCC, 0x78, 0x00]), // ò
+    ('\u{00F3}', [0x00, 0x1C, 0x00, 0x78, 0xCC, 0xCC, 0x78, 0x00]), // ó
+    ('\u{00F4}', [0x78, 0xCC, 0x00, 0x78, 0xCC, 0xCC, 0x78, 0x00]), // ô
+    ('\u{00F5}', [0x76, 0xDC, 0x00, 0x78, 0xCC, 0xCC, 0x78, 0x00]), // õ
+    ('\u{00F6}', [0x00, 0xCC, 0x00, 0x78, 0xCC, 0xCC, 0x78, 0x00]), // ö
+    ('\u{00F7}', [0x18, 0x18, 0x00, 0x7E, 0x00, 0x18, 0x18, 0x00]), // ÷
+    ('\u{00F8}', [0x00, 0x06, 0x3C, 0x6E, 0x7E, 0x76, 0x3C, 0x60]), // ø
+    ('\u{00F9}', [0x00, 0xE0, 0x00, 0xCC, 0xCC, 0xCC, 0x7E, 0x00]), // ù
+    ('\u{00FA}', [0x00, 0x1C, 0x00, 0xCC, 0xCC, 0xCC, 0x7E, 0x00]), // ú
+    ('\u{00FB}', [0x78, 0xCC, 0x00, 0xCC, 0xCC, 0xCC, 0x7E, 0x00]), // û
+    ('\u{00FC}', [0x00, 0xCC, 0x00, 0xCC, 0xCC, 0xCC, 0x7E, 0x00]), // ü
+    ('\u{00FD}', [0x00, 0x1C, 0x00, 0xCC, 0xCC, 0x7C, 0x0C, 0xF8]), // ý
+    ('\u{00FE}', [0x00, 0x00, 0x60, 0x7C, 0x66, 0x7C, 0x60, 0x00]), // þ
+    ('\u{00FF}', [0x00, 0xCC, 0x00, 0xCC, 0xCC, 0x7C, 0x0C, 0xF8]), // ÿ
+    ('\u{0192}', [0x0E, 0x1B, 0x18, 0x3C, 0x18, 0x18, 0xD8, 0x70]), // ƒ
+    ('\u{0393}', [0xFC, 0xCC, 0xC0, 0xC0, 0xC0, 0xC0, 0xC0, 0x00]), // Γ
+    ('\u{0398}', [0x38, 0x6C, 0xC6, 0xFE, 0xC6, 0x6C, 0x38, 0x00]), // Θ
+    ('\u{03A3}', [0xFE, 0xC6, 0x60, 0x30, 0x60, 0xC6, 0xFE, 0x00]), // Σ
+    ('\u{03A6}', [0x18, 0x7E, 0xDB, 0xDB, 0xDB, 0x7E, 0x18, 0x00]), // Φ
+    ('\u{03A9}', [0x7C, 0xC6, 0xC6, 0xC6, 0x6C, 0x6C, 0xEE, 0x00]), // Ω
+    ('\u{03B1}', [0x00, 0x00, 0x76, 0xDC, 0xC8, 0xDC, 0x76, 0x00]), // α
+    ('\u{03B4}', [0x1C, 0x30, 0x18, 0x7C, 0xCC, 0xCC, 0x78, 0x00]), // δ
+    ('\u{03B5}', [0x00, 0x00, 0x78, 0xC0, 0x70, 0xC0, 0x78, 0x00]), // ε
+    ('\u{03C0}', [0x00, 0x00, 0xFE, 0x6C, 0x6C, 0x6C, 0x6C, 0x00]), // π
+    ('\u{03C3}', [0x00, 0x00, 0x7E, 0xD8, 0xD8, 0xD8, 0x70, 0x00]), // σ
+    ('\u{03C4}', [0x00, 0x00, 0x7E, 0x18, 0x18, 0x1A, 0x0C, 0x00]), // τ
+    ('\u{03C6}', [0x00, 0x00, 0x6E, 0xDB, 0xDB, 0x7E, 0x18, 0x00]), // φ
+    ('\u{2022}', [0x00, 0x00, 0x18, 0x3C, 0x3C, 0x18, 0x00, 0x00]), // •
+    ('\u{203C}', [0x66, 0x66, 0x66, 0x66, 0x66, 0x00, 0x66, 0x00]), // ‼
+    ('\u{207F}', [0x78, 0x6C, 0x6C, 0x6C, 0x6C, 0x00, 0x00, 0x00]), // ⁿ
+    ('\u{20A7}', [0xF8, 0xCC, 0xCC, 0xFA, 0xC6, 0xCF, 0xC6, 0xC7]), // ₧
+    ('\u{2190}', [0x00, 0x30, 0x60, 0xFE, 0x60, 0x30, 0x00, 0x00]), // ←
+    ('\u{2191}', [0x18, 0x3C, 0x7E, 0x18, 0x18, 0x18, 0x18, 0x00]), // ↑
+    ('\u{2192}', [0x00, 0x18, 0x0C, 0xFE, 0x0C, 0x18, 0x00, 0x00]), // →
+    ('\u{2193}', [0x18, 0x18, 0x18, 0x18, 0x7E, 0x3C, 0x18, 0x00]), // ↓
+    ('\u{2194}', [0x00, 0x24, 0x66, 0xFF, 0x66, 0x24, 0x00, 0x00]), // ↔
+    ('\u{2195}', [0x18, 0x3C, 0x7E, 0x18, 0x18, 0x7E, 0x3C, 0x18]), // ↕
+    ('\u{21A8}', [0x18, 0x3C, 0x7E, 0x18, 0x7E, 0x3C, 0x18, 0xFF]), // ↨
+    ('\u{2219}', [0x00, 0x00, 0x00, 0x18, 0x18, 0x00, 0x00, 0x00]), // ∙
+    ('\u{221A}', [0x0F, 0x0C, 0x0C, 0x0C, 0xEC, 0x6C, 0x3C, 0x1C]), // √
+    ('\u{221E}', [0x00, 0x00, 0x7E, 0xDB, 0xDB, 0x7E, 0x00, 0x00]), // ∞
+    ('\u{221F}', [0x00, 0x00, 0xC0, 0xC0, 0xC0, 0xFE, 0x00, 0x00]), // ∟
+    ('\u{2229}', [0x78, 0xCC, 0xCC, 0xCC, 0xCC, 0xCC, 0xCC, 0x00]), // ∩
+    ('\u{2248}', [0x00, 0x76, 0xDC, 0x00, 0x76, 0xDC, 0x00, 0x00]), // ≈
+    ('\u{2261}', [0x00, 0xFC, 0x00, 0xFC, 0x00, 0xFC, 0x00, 0x00]), // ≡
+    ('\u{2264}', [0x0C, 0x18, 0x30, 0x18, 0x0C, 0x00, 0x7E, 0x00]), // ≤
+    ('\u{2265}', [0x30, 0x18, 0x0C, 0x18, 0x30, 0x00, 0x7E, 0x00]), // ≥
+    ('\u{2302}', [0x00, 0x10, 0x38, 0x6C, 0xC6, 0xC6, 0xFE, 0x00]), // ⌂
+    ('\u{2310}', [0x00, 0x00, 0x00, 0xFC, 0xC0, 0xC0, 0x00, 0x00]), // ⌐
+    ('\u{2320}', [0x0E, 0x1B, 0x1B, 0x18, 0x18, 0x18, 0x18, 0x18]), // ⌠
+    ('\u{2321}', [0x18, 0x18, 0x18, 0x18, 0x18, 0xD8, 0xD8, 0x70]), // ⌡
+    ('\u{2500}', [0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00]), // ─
+    ('\u{2502}', [0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10]), // │
+    ('\u{250C}', [0x00, 0x00, 0x00, 0x00, 0x1F, 0x10, 0x10, 0x10]), // ┌
+    ('\u{2510}', [0x00, 0x00, 0x00, 0x00, 0xF0, 0x10, 0x10, 0x10]), // ┐
+    ('\u{2514}', [0x10, 0x10, 0x10, 0x10, 0x1F, 0x00, 0x00, 0x00]), // └
+    ('\u{2518}', [0x10, 0x10, 0x10, 0x10, 0xF0, 0x00, 0x00, 0x00]), // ┘
+    ('\u{251C}', [0x10, 0x10, 0x10, 0x10, 0x1F, 0x10, 0x10, 0x10]), // ├
+    ('\u{2524}', [0x10, 0x10, 0x10, 0x10, 0xF0, 0x10, 0x10, 0x10]), // ┤
+    ('\u{252C}', [0x00, 0x00, 0x00, 0x00, 0xFF, 0x10, 0x10, 0x10]), // ┬
+    ('\u{2534}', [0x10, 0x10, 0x10, 0x10, 0xFF, 0x00, 0x00, 0x00]), // ┴
+    ('\u{253C}', [0x10, 0x10, 0x10, 0x10, 0xFF, 0x10, 0x10, 0x10]), // ┼
+    ('\u{2550}', [0x00, 0x00, 0x00, 0xFF, 0x00, 0xFF, 0x00, 0x00]), // ═
+    ('\u{2551}', [0x28, 0x28, 0x28, 0x28, 0x28, 0x28, 0x28, 0x28]), // ║
+    ('\u{2552}', [0x00, 0x00, 0x00, 0x1F, 0x10, 0x1F, 0x10, 0x10]), // ╒
+    ('\u{2553}', [0x00, 0x00, 0x00, 0x00, 0x3F, 0x28, 0x28, 0x28]), // ╓
+    ('\u{2554}', [0x00, 0x00, 0x00, 0x3F, 0x20, 0x2F, 0x28, 0x28]), // ╔
+    ('\u{2555}', [0x00, 0x00, 0x00, 0xF0, 0x10, 0xF0, 0x10, 0x10]), // ╕
+    ('\u{2556}', [0x00, 0x00, 0x00, 0x00, 0xF8, 0x28, 0x28, 0x28]), // ╖
+    ('\u{2557}', [0x00, 0x00, 0x00, 0xF8, 0x08, 0xE8, 0x28, 0x28]), // ╗
+    ('\u{2558}', [0x10, 0x10, 0x10, 0x1F, 0x10, 0x1F, 0x00, 0x00]), // ╘
+    ('\u{2559}', [0x28, 0x28, 0x28, 0x28, 0x3F, 0x00, 0x00, 0x00]), // ╙
+    ('\u{255A}', [0x28, 0x28, 0x28, 0x2F, 0x20, 0x3F, 0x00, 0x00]), // ╚
+    ('\u{255B}', [0x10, 0x10, 0x10, 0xF0, 0x10, 0xF0, 0x00, 0x00]), // ╛
+    ('\u{255C}', [0x28, 0x28, 0x28, 0x28, 0xF8, 0x00, 0x00, 0x00]), // ╜
+    ('\u{255D}', [0x28, 0x28, 0x28, 0xE8, 0x08, 0xF8, 0x00, 0x00]), // ╝
+    ('\u{255E}', [0x10, 0x10, 0x10, 0x1F, 0x10, 0x1F, 0x10, 0x10]), // ╞
+    ('\u{255F}', [0x28, 0x28, 0x28, 0x28, 0x2F, 0x28, 0x28, 0x28]), // ╟
+    ('\u{2560}', [0x28, 0x28, 0x28, 0x2F, 0x20, 0x2F, 0x28, 0x28]), // ╠
+    ('\u{2561}', [0x10, 0x10, 0x10, 0xF0, 0x10, 0xF0, 0x10, 0x10]), // ╡
+    ('\u{2562}', [0x28, 0x28, 0x28, 0x28, 0xE8, 0x28, 0x28, 0x28]), // ╢
+    ('\u{2563}', [0x28, 0x28, 0x28, 0xE8, 0x08, 0xE8, 0x28, 0x28]), // ╣
+    ('\u{2564}', [0x00, 0x00, 0x00, 0xFF, 0x00, 0xFF, 0x10, 0x10]), // ╤
+    ('\u{2565}', [0x00, 0x00, 0x00, 0x00, 0xFF, 0x28, 0x28, 0x28]), // ╥
+    ('\u{2566}', [0x00, 0x00, 0x00, 0xFF, 0x00, 0xEF, 0x28, 0x28]), // ╦
+    ('\u{2567}', [0x10, 0x10, 0x10, 0xFF, 0x00, 0xFF, 0x00, 0x00]), // ╧
+    ('\u{2568}', [0x28, 0x28, 0x28, 0x28, 0xFF, 0x00, 0x00, 0x00]), // ╨
+    ('\u{2569}', [0x28, 0x28, 0x28, 0xEF, 0x00, 0xFF, 0x00, 0x00]), // ╩
+    ('\u{256A}', [0x10, 0x10, 0x10, 0xFF, 0x10, 0xFF, 0x10, 0x10]), // ╪
+    ('\u{256B}', [0x28, 0x28, 0x28, 0x28, 0xFF, 0x28, 0x28, 0x28]), // ╫
+    ('\u{256C}', [0x28, 0x28, 0x28, 0xEF, 0x00, 0xEF, 0x28, 0x28]), // ╬
+    ('\u{2580}', [0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00]), // ▀
+    ('\u{2584}', [0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF]), // ▄
+    ('\u{2588}', [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]), // █
+    ('\u{258C}', [0xF0, 0xF0, 0xF0, 0xF0, 0xF0, 0xF0, 0xF0, 0xF0]), // ▌
+    ('\u{2590}', [0x0F, 0x0F, 0x0F, 0x0F, 0x0F, 0x0F, 0x0F, 0x0F]), // ▐
+    ('\u{2591}', [0xAA, 0x00, 0x55, 0x00, 0xAA, 0x00, 0x55, 0x00]), // ░
+    ('\u{2592}', [0xAA, 0x55, 0xAA, 0x55, 0xAA, 0x55, 0xAA, 0x55]), // ▒
+    ('\u{2593}', [0xFF, 0x55, 0xFF, 0xAA, 0xFF, 0x55, 0xFF, 0xAA]), // ▓
+    ('\u{25A0}', [0x00, 0x00, 0x3C, 0x3C, 0x3C, 0x3C, 0x00, 0x00]), // ■
+    ('\u{25AC}', [0x00, 0x00, 0x00, 0x00, 0x7E, 0x7E, 0x7E, 0x00]), // ▬
+    ('\u{25B2}', [0x00, 0x18, 0x3C, 0x7E, 0xFF, 0xFF, 0x00, 0x00]), // ▲
+    ('\u{25BA}', [0x80, 0xE0, 0xF8, 0xFE, 0xF8, 0xE0, 0x80, 0x00]), // ►
+    ('\u{25BC}', [0x00, 0xFF, 0xFF, 0x7E, 0x3C, 0x18, 0x00, 0x00]), // ▼
+    ('\u{25C4}', [0x02, 0x0E, 0x3E, 0xFE, 0x3E, 0x0E, 0x02, 0x00]), // ◄
+    ('\u{25CB}', [0x00, 0x3C, 0x66, 0x42, 0x42, 0x66, 0x3C, 0x00]), // ○
+    ('\u{25D8}', [0xFF, 0xFF, 0xE7, 0xC3, 0xC3, 0xE7, 0xFF, 0xFF]), // ◘
+    ('\u{263A}', [0x7E, 0x81, 0xA5, 0x81, 0xBD, 0x99, 0x81, 0x7E]), // ☺
+    ('\u{263B}', [0x7E, 0xFF, 0xDB, 0xFF, 0xC3, 0xE7, 0xFF, 0x7E]), // ☻
+    ('\u{263C}', [0x99, 0x5A, 0x3C, 0xE7, 0xE7, 0x3C, 0x5A, 0x99]), // ☼
+    ('\u{2640}', [0x3C, 0x66, 0x66, 0x66, 0x3C, 0x18, 0x7E, 0x18]), // ♀
+    ('\u{2642}', [0x0F, 0x07, 0x0F, 0x7D, 0xCC, 0xCC, 0xCC, 0x78]), // ♂
+    ('\u{2660}', [0x10, 0x10, 0x38, 0x7C, 0xFE, 0x7C, 0x38, 0x7C]), // ♠
+    ('\u{2663}', [0x38, 0x7C, 0x38, 0xFE, 0xFE, 0x7C, 0x38, 0x7C]), // ♣
+    ('\u{2665}', [0x6C, 0xFE, 0xFE, 0xFE, 0x7C, 0x38, 0x10, 0x00]), // ♥
+    ('\u{2666}', [0x10, 0x38, 0x7C, 0xFE, 0x7C, 0x38, 0x10, 0x00]), // ♦
+    ('\u{266A}', [0x3F, 0x33, 0x3F, 0x30, 0x30, 0x70, 0xF0, 0xE0]), // ♪
+    ('\u{266B}', [0x7F, 0x63, 0x7F, 0x63, 0x63, 0x67, 0xE6, 0xC0]), // ♫
+];
+
+/// The glyph used for characters that are not in the font.
+const REPLACEMENT: char = '?';
+
+/// Returns the 8x8 pixel glyph for the provided character.
+///
+/// Characters that are not part of CP-437 or Latin-1 are replaced with `'?'`.
+pub(crate) fn glyph(char: char) -> &'static [u8; 8] {
+    if let ' '..='~' = char {
+        return &GLYPHS[char as usize - ' ' as usize];
+    }
+    match EXTENDED_GLYPHS.binary_search_by_key(&char, |(char, _)| *char) {
+        Ok(index) => &EXTENDED_GLYPHS[index].1,
+        Err(_) => &GLYPHS[REPLACEMENT as usize - ' ' as usize],
+    }
+}
+
+impl Bitmap {
+    /// Renders one line of text with the built-in 8x8 font.
+    ///
+    /// Each character takes up one tile, which is the same grid the display uses for text.
+    /// Like the font built into the display, it covers all characters of CP-437.
+    /// The glyphs may differ in details, as they are based on the IBM PC 8x8 font.
+    /// Latin-1 characters are available as well, all other characters are rendered as `'?'`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use servicepoint::*;
+    /// let bitmap = Bitmap::from_text("Hello");
+    /// assert_eq!(bitmap.width(), 5 * TILE_SIZE);
+    /// assert_eq!(bitmap.height(), TILE_SIZE);
+    /// ```
+    #[must_use]
+    pub fn from_text(text: &str) -> Bitmap {
+        let chars: Vec<_> = text.chars().collect();
+        #[allow(
+            clippy::unwrap_used,
+            reason = "the width is always a multiple of 8"
+        )]
+        let mut bitmap =
+            Bitmap::new(chars.len() * TILE_SIZE, TILE_SIZE).unwrap();
+        let row_bytes = chars.len();
+        let data = bitmap.data_ref_mut();
+        for (column, char) in chars.into_iter().enumerate() {
+            for (row, byte) in glyph(char).iter().enumerate() {
+                data[row * row_bytes + column] = *byte;
+            }
+        }
+        bitmap
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        font::{glyph, EXTENDED_GLYPHS},
+        Bitmap, DataRef, Grid,
+    };
+
+    #[test]
+    fn replacement() {
+        assert_eq!(glyph('€'), glyph('?'));
+        assert_eq!(glyph('\n'), glyph('?'));
+        assert_ne!(glyph('a'), glyph('?'));
+        assert_eq!(glyph(' '), &[0; 8]);
+    }
+
+    #[test]
+    fn extended() {
+        assert!(EXTENDED_GLYPHS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        for char in ['ä', 'Ö', 'ß', 'é', '░', '╬', '☺', 'Σ', '√', '\u{a0}']
+        {
+            assert_ne!(glyph(char), glyph('?'), "{char}");
+        }
+    }
+
+    #[cfg(feature = "cp437")]
+    #[test]
+    fn covers_cp437() {
+        for byte in (0..=u8::MAX).filter(|byte| !matches!(byte, 0 | b'\n')) {
+            let char = crate::cp437::cp437_to_char(byte);
+            assert!(
+                char == '?' || glyph(char) != glyph('?'),
+                "missing {char} ({byte:#x})"
+            );
+        }
+    }
+
+    #[test]
+    fn from_text() {
+        let bitmap = Bitmap::from_text("_|");
+        assert_eq!(bitmap.width(), 16);
+        assert_eq!(&bitmap.data_ref()[12..16], [0x00, 0x18, 0xFF, 0x00]);
+        assert!(bitmap.get(11, 0) && !bitmap.get(10, 0));
+        assert!(bitmap.get(0, 7) && !bitmap.get(0, 6));
+
+        assert_eq!(Bitmap::from_text("").width(), 0);
+    }
+}
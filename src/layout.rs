@@ -0,0 +1,385 @@
+use crate::{
+    origin::DisplayUnit, GridMut, Orientation, Origin, Region, Tiles, WindowMut,
+};
+
+/// How much space a pane of a [Layout] takes up along the [`Orientation`] of the layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Constraint {
+    /// exactly this size
+    Fixed(usize),
+    /// this percentage of the available size, values above 100 are treated as 100
+    Percentage(usize),
+    /// this fraction of the available size, e.g. `Ratio(1, 3)` for a third
+    Ratio(usize, usize),
+    /// at least this size, growing to fill the remaining space
+    Min(usize),
+    /// at most this size, growing to fill the remaining space
+    Max(usize),
+}
+
+/// Divides an area into named panes according to [Constraint]s.
+///
+/// Space is handed out in order: first [`Constraint::Fixed`], [`Constraint::Percentage`],
+/// [`Constraint::Ratio`] and the minimum of [`Constraint::Min`] are taken,
+/// then the remaining space is shared evenly between [`Constraint::Min`] and [`Constraint::Max`] panes.
+/// If the area is too small, the last panes shrink first.
+///
+/// Layouts can be nested by splitting one of the resulting regions again.
+///
+/// # Examples
+///
+/// ```rust
+/// # use servicepoint::*;
+/// let layout = Layout::new(
+///     Orientation::Vertical,
+///     [("title", Constraint::Fixed(1)), ("content", Constraint::Min(0))],
+/// );
+///
+/// let mut grid = CharGrid::new(TILE_WIDTH, TILE_HEIGHT);
+/// let mut panes = layout.split_mut(&mut grid);
+/// panes.get_mut("title").unwrap().set_row_str(0, "Status").unwrap();
+/// panes.get_mut("content").unwrap().fill('.');
+///
+/// let regions = layout.split(&Region::<Pixels>::new(Origin::ZERO, PIXEL_WIDTH, PIXEL_HEIGHT));
+/// assert_eq!(regions.get("title").unwrap().height, 1);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Layout {
+    /// the axis along which the panes are placed
+    pub orientation: Orientation,
+    /// the name and constraint of each pane, in order
+    pub panes: Vec<(String, Constraint)>,
+    /// space left free on all sides of the area
+    pub margin: usize,
+    /// space left free between two panes
+    pub spacing: usize,
+}
+
+impl Layout {
+    /// Creates a layout without margin or spacing.
+    #[must_use]
+    pub fn new(
+        orientation: Orientation,
+        panes: impl IntoIterator<Item = (impl Into<String>, Constraint)>,
+    ) -> Self {
+        Self {
+            orientation,
+            panes: panes
+                .into_iter()
+                .map(|(name, constraint)| (name.into(), constraint))
+                .collect(),
+            margin: 0,
+            spacing: 0,
+        }
+    }
+
+    /// Divides the area into regions, one per pane.
+    ///
+    /// Works in both [`crate::Tiles`] and [`crate::Pixels`].
+    /// The regions never extend beyond the area. If it is too small, the affected panes are empty.
+    #[must_use]
+    pub fn split<Unit: DisplayUnit>(
+        &self,
+        area: &Region<Unit>,
+    ) -> Panes<Region<Unit>> {
+        let width = area.width.saturating_sub(2 * self.margin);
+        let height = area.height.saturating_sub(2 * self.margin);
+        let (length, cross) = match self.orientation {
+            Orientation::Horizontal => (width, height),
+            Orientation::Vertical => (height, width),
+        };
+
+        let right = area.origin.x + area.width;
+        let bottom = area.origin.y + area.height;
+        let mut offset = 0;
+        let mut panes = Vec::with_capacity(self.panes.len());
+        for ((name, _), size) in self.panes.iter().zip(self.sizes(length)) {
+            let start = offset.min(length);
+            let (x, y, width, height) = match self.orientation {
+                Orientation::Horizontal => (start, 0, size, cross),
+                Orientation::Vertical => (0, start, cross, size),
+            };
+            // keep panes inside of the area, even if the margin does not fit
+            let x = (area.origin.x + self.margin + x).min(right);
+            let y = (area.origin.y + self.margin + y).min(bottom);
+            let region = Region::new(
+                Origin::new(x, y),
+                width.min(right - x),
+                height.min(bottom - y),
+            );
+            panes.push((name.clone(), region));
+            offset += size + self.spacing;
+        }
+        Panes(panes)
+    }
+
+    /// Divides the grid into windows, one per pane.
+    ///
+    /// To divide a part of a grid, pass a [`WindowMut`] into it.
+    #[must_use]
+    pub fn split_mut<'t, TElement: Copy, TGrid: GridMut<TElement>>(
+        &self,
+        grid: &'t mut TGrid,
+    ) -> Panes<WindowMut<'t, TElement, TGrid>> {
+        let area =
+            Region::<Tiles>::new(Origin::ZERO, grid.width(), grid.height());
+        let Panes(regions) = self.split(&area);
+        let parts = regions
+            .iter()
+            .map(|(_, region)| (region.xs(), region.ys()))
+            .collect::<Vec<_>>();
+        let Some(windows) = WindowMut::new(grid, .., ..)
+            .and_then(|window| window.split_disjoint(&parts))
+        else {
+            unreachable!("layout panes are disjoint and inside of the grid")
+        };
+        Panes(
+            regions
+                .into_iter()
+                .map(|(name, _)| name)
+                .zip(windows)
+                .collect(),
+        )
+    }
+
+    /// The size of each pane along the orientation.
+    fn sizes(&self, length: usize) -> Vec<usize> {
+        let gaps = self.spacing * self.panes.len().saturating_sub(1);
+        let available = length.saturating_sub(gaps);
+
+        let mut sizes = self
+            .panes
+            .iter()
+            .map(|(_, constraint)| match *constraint {
+                Constraint::Fixed(size) | Constraint::Min(size) => size,
+                Constraint::Percentage(percent) => {
+                    available * percent.min(100) / 100
+                }
+                Constraint::Ratio(_, 0) | Constraint::Max(_) => 0,
+                Constraint::Ratio(numerator, denominator) => {
+                    available * numerator.min(denominator) / denominator
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut rest = available.saturating_sub(sizes.iter().sum());
+        while rest > 0 {
+            let growable = self
+                .panes
+                .iter()
+                .zip(&sizes)
+                .enumerate()
+                .filter(|(_, ((_, constraint), size))| match constraint {
+                    Constraint::Min(_) => true,
+                    Constraint::Max(max) => *size < max,
+                    _ => false,
+                })
+                .map(|(index, _)| index)
+                .collect::<Vec<_>>();
+            if growable.is_empty() {
+                break;
+            }
+
+            let share = rest / growable.len();
+            let extra = rest % growable.len();
+            for (position, index) in growable.into_iter().enumerate() {
+                let mut grow = share + usize::from(position < extra);
+                if let Constraint::Max(max) = self.panes[index].1 {
+                    grow = grow.min(max - sizes[index]);
+                }
+                sizes[index] += grow;
+                rest -= grow;
+            }
+        }
+
+        let mut excess = sizes.iter().sum::<usize>().saturating_sub(available);
+        for size in sizes.iter_mut().rev() {
+            let shrink = excess.min(*size);
+            *size -= shrink;
+            excess -= shrink;
+        }
+        sizes
+    }
+}
+
+/// The result of splitting a [Layout], accessible by the pane names.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Panes<T>(Vec<(String, T)>);
+
+impl<T> Panes<T> {
+    /// Gets the pane with the provided name.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&T> {
+        self.0
+            .iter()
+            .find(|(pane, _)| pane == name)
+            .map(|(_, value)| value)
+    }
+
+    /// Gets the pane with the provided name mutably.
+    #[must_use]
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut T> {
+        self.0
+            .iter_mut()
+            .find(|(pane, _)| pane == name)
+            .map(|(_, value)| value)
+    }
+
+    /// Takes the pane with the provided name out, e.g. to pass it to a separate component.
+    pub fn remove(&mut self, name: &str) -> Option<T> {
+        let index = self.0.iter().position(|(pane, _)| pane == name)?;
+        Some(self.0.remove(index).1)
+    }
+
+    /// Iterates over the names and panes in layout order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &T)> {
+        self.0.iter().map(|(name, value)| (name.as_str(), value))
+    }
+}
+
+impl<T> IntoIterator for Panes<T> {
+    type Item = (String, T);
+    type IntoIter = std::vec::IntoIter<(String, T)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        CharGrid, CharGridExt, Constraint, Grid, GridMut, Layout, Orientation,
+        Origin, Pixels, Region, Tiles,
+    };
+
+    fn widths(layout: &Layout, width: usize) -> Vec<usize> {
+        layout
+            .split(&Region::<Tiles>::new(Origin::ZERO, width, 1))
+            .iter()
+            .map(|(_, region)| region.width)
+            .collect()
+    }
+
+    #[test]
+    fn fixed_percentage_ratio() {
+        let layout = Layout::new(
+            Orientation::Horizontal,
+            [
+                ("a", Constraint::Fixed(3)),
+                ("b", Constraint::Percentage(50)),
+                ("c", Constraint::Ratio(1, 4)),
+            ],
+        );
+        assert_eq!(widths(&layout, 20), [3, 10, 5]);
+    }
+
+    #[test]
+    fn min_and_max_share_rest() {
+        let layout = Layout::new(
+            Orientation::Horizontal,
+            [
+                ("a", Constraint::Min(2)),
+                ("b", Constraint::Max(3)),
+                ("c", Constraint::Min(0)),
+            ],
+        );
+        assert_eq!(widths(&layout, 12), [6, 3, 3]);
+        assert_eq!(widths(&layout, 5), [3, 1, 1]);
+    }
+
+    #[test]
+    fn too_small_shrinks_last() {
+        let layout = Layout::new(
+            Orientation::Horizontal,
+            [("a", Constraint::Fixed(4)), ("b", Constraint::Fixed(4))],
+        );
+        assert_eq!(widths(&layout, 6), [4, 2]);
+        assert_eq!(widths(&layout, 3), [3, 0]);
+    }
+
+    #[test]
+    fn margin_and_spacing() {
+        let mut layout = Layout::new(
+            Orientation::Vertical,
+            [
+                ("top", Constraint::Fixed(2)),
+                ("bottom", Constraint::Min(0)),
+            ],
+        );
+        layout.margin = 1;
+        layout.spacing = 1;
+        let panes =
+            layout.split(&Region::<Pixels>::new(Origin::new(8, 16), 16, 10));
+        assert_eq!(
+            panes.get("top"),
+            Some(&Region::new(Origin::new(9, 17), 14, 2))
+        );
+        assert_eq!(
+            panes.get("bottom"),
+            Some(&Region::new(Origin::new(9, 20), 14, 5))
+        );
+        assert_eq!(panes.get("missing"), None);
+    }
+
+    #[test]
+    fn split_mut() {
+        let mut layout = Layout::new(
+            Orientation::Horizontal,
+            [
+                ("left", Constraint::Fixed(2)),
+                ("right", Constraint::Min(0)),
+            ],
+        );
+        layout.spacing = 1;
+        let mut grid = CharGrid::new(5, 2);
+        grid.fill('.');
+
+        let mut panes = layout.split_mut(&mut grid);
+        let mut right = panes.remove("right").unwrap();
+        panes.get_mut("left").unwrap().fill('l');
+        right.fill('r');
+        assert_eq!(right.width(), 2);
+        assert!(panes.remove("right").is_none());
+
+        assert_eq!(grid.get_row_str(0), Some("ll.rr".to_owned()));
+        assert_eq!(grid.get_row_str(1), Some("ll.rr".to_owned()));
+    }
+
+    #[test]
+    fn panes_on_threads() {
+        let layout = Layout::new(
+            Orientation::Horizontal,
+            [("a", Constraint::Fixed(1)), ("b", Constraint::Min(0))],
+        );
+        let mut grid = CharGrid::new(3, 1);
+        std::thread::scope(|scope| {
+            for (name, mut pane) in layout.split_mut(&mut grid) {
+                scope.spawn(move || {
+                    pane.fill(if name == "a" { 'a' } else { 'b' })
+                });
+            }
+        });
+        assert_eq!(String::from(grid), "abb");
+    }
+
+    #[test]
+    fn margin_larger_than_area() {
+        let mut layout = Layout::new(
+            Orientation::Vertical,
+            [("a", Constraint::Fixed(1)), ("b", Constraint::Min(0))],
+        );
+        layout.margin = 3;
+        let panes =
+            layout.split(&Region::<Tiles>::new(Origin::new(1, 1), 4, 2));
+        assert_eq!(panes.get("a"), Some(&Region::new(Origin::new(4, 3), 0, 0)));
+
+        let mut grid = CharGrid::new(4, 2);
+        grid.fill('.');
+        let mut panes = layout.split_mut(&mut grid);
+        let pane = panes.get_mut("b").unwrap();
+        assert_eq!((pane.width(), pane.height()), (0, 0));
+        pane.fill('x');
+        assert_eq!(String::from(grid), "....\n....");
+    }
+}
@@ -81,16 +81,33 @@
 pub use crate::brightness::Brightness;
 pub use crate::command_code::CommandCode;
 pub use crate::commands::*;
+pub use crate::compositor::{Compositor, Layer};
 pub use crate::compression_code::CompressionCode;
 pub use crate::connection::*;
 pub use crate::constants::*;
 pub use crate::containers::*;
+pub use crate::diff::GridDiff;
+pub use crate::display::{Display, TRANSPARENT_CHAR};
+pub use crate::dithering::DitheredFrames;
+pub use crate::effects::BrightnessEffect;
+pub use crate::layout::{Constraint, Layout, Panes};
 pub use crate::origin::{Origin, Pixels, Tiles};
 pub use crate::packet::{Header, Packet, Payload};
+pub use crate::physical::PhysicalLayout;
+#[cfg(feature = "ratatui")]
+pub use crate::ratatui_backend::{DisplayBackend, FlushFailedError};
+pub use crate::region::Region;
+pub use crate::sprite::{LoopMode, Sprite, SpriteFrame, Timeline};
+pub use crate::text_layout::{
+    HorizontalAlignment, TextLayout, VerticalAlignment,
+};
+pub use crate::ticker::{Ticker, TickerFrame};
+pub use crate::widgets::*;
 
 mod brightness;
 mod command_code;
 mod commands;
+mod compositor;
 mod compression;
 mod compression_code;
 mod connection;
@@ -98,8 +115,22 @@ mod constants;
 mod containers;
 #[cfg(feature = "cp437")]
 pub mod cp437;
+mod diff;
+mod display;
+mod dithering;
+mod effects;
+mod font;
+mod layout;
 mod origin;
 mod packet;
+mod physical;
+#[cfg(feature = "ratatui")]
+mod ratatui_backend;
+mod region;
+mod sprite;
+mod text_layout;
+mod ticker;
+mod widgets;
 
 // include README.md in doctest
 #[doc = include_str!("../README.md")]
@@ -0,0 +1,228 @@
+use crate::{Bitmap, Grid, GridMut, PIXEL_HEIGHT, PIXEL_WIDTH, TILE_SIZE};
+
+/// Maps between logical display pixels and their physical position on the panel.
+///
+/// The display has a gap between each row of tiles (see `about_display.md`).
+/// Because of that, an image that is sent as-is looks stretched vertically,
+/// e.g. circles become ovals.
+///
+/// Physical coordinates are measured in pixel sizes, including the gaps.
+/// Logical coordinates are the ones used in [Bitmap]s sent to the display.
+///
+/// # Examples
+///
+/// ```rust
+/// # use servicepoint::*;
+/// # let connection = FakeConnection;
+/// let layout = PhysicalLayout::default();
+///
+/// // draw a circle in physical coordinates
+/// let mut image = ValueGrid::new(layout.physical_width(), layout.physical_height());
+/// let (center_x, center_y) = (image.width() / 2, image.height() / 2);
+/// for (x, y, _) in image.clone().enumerate() {
+///     let dx = x.abs_diff(center_x);
+///     let dy = y.abs_diff(center_y);
+///     image.set(x, y, dx * dx + dy * dy < 50 * 50);
+/// }
+///
+/// // the circle looks round on the real display
+/// let bitmap = layout.resample(&image).unwrap();
+/// connection.send_command(BitmapCommand::from(bitmap)).unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PhysicalLayout {
+    /// The gap between two rows of tiles, measured in pixels.
+    pub tile_row_gap: usize,
+}
+
+impl PhysicalLayout {
+    /// The approximate gap between tile rows of the real display.
+    pub const DEFAULT_TILE_ROW_GAP: usize = 4;
+
+    /// Creates a layout with the specified gap between tile rows.
+    #[must_use]
+    pub fn new(tile_row_gap: usize) -> Self {
+        Self { tile_row_gap }
+    }
+
+    /// The physical width of the whole display.
+    ///
+    /// As there are no gaps between tile columns, this is the same as [`PIXEL_WIDTH`].
+    #[must_use]
+    pub fn physical_width(&self) -> usize {
+        PIXEL_WIDTH
+    }
+
+    /// The physical height of the whole display, including the gaps.
+    #[must_use]
+    pub fn physical_height(&self) -> usize {
+        self.physical_height_of(PIXEL_HEIGHT)
+    }
+
+    /// The physical height of an area starting at the top of a tile row with the specified logical height.
+    #[must_use]
+    pub fn physical_height_of(&self, logical_height: usize) -> usize {
+        if logical_height == 0 {
+            return 0;
+        }
+        let gaps = (logical_height - 1) / TILE_SIZE;
+        logical_height + gaps * self.tile_row_gap
+    }
+
+    /// The logical height of the rows fully visible in a physical area starting at the top of a tile row.
+    ///
+    /// This is the inverse of [`Self::physical_height_of`].
+    #[must_use]
+    pub fn logical_height_of(&self, physical_height: usize) -> usize {
+        let stride = TILE_SIZE + self.tile_row_gap;
+        let full_tiles = physical_height / stride;
+        let remainder = physical_height % stride;
+        full_tiles * TILE_SIZE + remainder.min(TILE_SIZE)
+    }
+
+    /// Converts a logical position into its physical position.
+    #[must_use]
+    pub fn to_physical(&self, x: usize, y: usize) -> (usize, usize) {
+        (x, y + (y / TILE_SIZE) * self.tile_row_gap)
+    }
+
+    /// Converts a physical position into a logical position.
+    ///
+    /// returns: None if the position is inside of a gap
+    #[must_use]
+    pub fn to_logical(&self, x: usize, y: usize) -> Option<(usize, usize)> {
+        let stride = TILE_SIZE + self.tile_row_gap;
+        let tile_row = y / stride;
+        let row_in_tile = y % stride;
+        if row_in_tile >= TILE_SIZE {
+            return None;
+        }
+        Some((x, tile_row * TILE_SIZE + row_in_tile))
+    }
+
+    /// Resamples an image in physical coordinates into a [Bitmap] that looks geometrically correct on the panel.
+    ///
+    /// Every logical pixel takes the value of the pixel at its physical position.
+    /// Image rows that fall into a gap are not visible.
+    /// The height of the result is [`Self::logical_height_of`] the image height.
+    ///
+    /// returns: None if the image width is not a multiple of 8
+    #[must_use]
+    pub fn resample(&self, image: &impl Grid<bool>) -> Option<Bitmap> {
+        let mut result =
+            Bitmap::new(image.width(), self.logical_height_of(image.height()))?;
+        for y in 0..result.height() {
+            let (_, physical_y) = self.to_physical(0, y);
+            for x in 0..result.width() {
+                result.set(x, y, image.get(x, physical_y));
+            }
+        }
+        Some(result)
+    }
+
+    /// Expands a [Bitmap] into physical coordinates, e.g. for previewing how it will look on the panel.
+    ///
+    /// Pixels in the gaps are off.
+    #[must_use]
+    pub fn expand(&self, bitmap: &Bitmap) -> Bitmap {
+        #[allow(
+            clippy::unwrap_used,
+            reason = "the width does not change and is already valid"
+        )]
+        let mut result = Bitmap::new(
+            bitmap.width(),
+            self.physical_height_of(bitmap.height()),
+        )
+        .unwrap();
+        for y in 0..bitmap.height() {
+            let (_, physical_y) = self.to_physical(0, y);
+            for x in 0..bitmap.width() {
+                result.set(x, physical_y, bitmap.get(x, y));
+            }
+        }
+        result
+    }
+}
+
+impl Default for PhysicalLayout {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_TILE_ROW_GAP)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Bitmap, Grid, GridMut, PhysicalLayout, ValueGrid, PIXEL_HEIGHT,
+        TILE_HEIGHT,
+    };
+
+    #[test]
+    fn sizes() {
+        let layout = PhysicalLayout::default();
+        assert_eq!(
+            layout.physical_height(),
+            PIXEL_HEIGHT + (TILE_HEIGHT - 1) * 4
+        );
+        assert_eq!(
+            layout.logical_height_of(layout.physical_height()),
+            PIXEL_HEIGHT
+        );
+        assert_eq!(layout.physical_height_of(8), 8);
+        assert_eq!(layout.physical_height_of(9), 13);
+        assert_eq!(layout.logical_height_of(10), 8);
+        assert_eq!(layout.logical_height_of(13), 9);
+
+        let no_gap = PhysicalLayout::new(0);
+        assert_eq!(no_gap.physical_height(), PIXEL_HEIGHT);
+    }
+
+    #[test]
+    fn convert_positions() {
+        let layout = PhysicalLayout::new(4);
+        assert_eq!(layout.to_physical(3, 7), (3, 7));
+        assert_eq!(layout.to_physical(3, 8), (3, 12));
+        assert_eq!(layout.to_physical(0, 17), (0, 25));
+
+        assert_eq!(layout.to_logical(3, 7), Some((3, 7)));
+        assert_eq!(layout.to_logical(3, 8), None);
+        assert_eq!(layout.to_logical(3, 11), None);
+        assert_eq!(layout.to_logical(3, 12), Some((3, 8)));
+        for y in 0..PIXEL_HEIGHT {
+            let (x, physical_y) = layout.to_physical(1, y);
+            assert_eq!(layout.to_logical(x, physical_y), Some((1, y)));
+        }
+    }
+
+    #[test]
+    fn resample() {
+        let layout = PhysicalLayout::new(2);
+        // every physical row is on, except for the ones in the gaps
+        let mut image = ValueGrid::new(8, 20);
+        for y in 0..image.height() {
+            if layout.to_logical(0, y).is_some() {
+                image.set_row(y, &[true; 8]).unwrap();
+            }
+        }
+
+        let bitmap = layout.resample(&image).unwrap();
+        assert_eq!(bitmap.height(), 16);
+        assert!(bitmap.all());
+
+        assert_eq!(layout.resample(&ValueGrid::<bool>::new(7, 2)), None);
+    }
+
+    #[test]
+    fn expand_round_trip() {
+        let layout = PhysicalLayout::default();
+        let mut bitmap = Bitmap::new(16, 24).unwrap();
+        bitmap.set(3, 9, true);
+        bitmap.set(15, 23, true);
+
+        let expanded = layout.expand(&bitmap);
+        assert_eq!(expanded.height(), 32);
+        assert!(expanded.get(3, 13));
+        assert_eq!(expanded.count_ones(), 2);
+        assert_eq!(layout.resample(&expanded).unwrap(), bitmap);
+    }
+}
@@ -0,0 +1,394 @@
+use crate::{
+    Brightness, BrightnessGrid, BrightnessGridCommand, CharGrid,
+    CharGridCommand, Connection, Grid, GridDiff, GridMut, Packet, Tiles,
+    PIXEL_HEIGHT, PIXEL_WIDTH, TILE_HEIGHT, TILE_WIDTH,
+};
+use ratatui_core::{
+    backend::{Backend, ClearType, WindowSize},
+    buffer::Cell,
+    layout::{Position, Size},
+    style::Modifier,
+};
+use std::ops::Range;
+
+/// Sending the changes to the display failed.
+#[derive(Debug, thiserror::Error, Eq, PartialEq)]
+#[error("The changes could not be sent to the display.")]
+pub struct FlushFailedError;
+
+/// A [ratatui](https://ratatui.rs) backend drawing onto the display, one character per tile.
+///
+/// Drawing only changes the local state. Only the tiles that changed since the last flush
+/// are sent to the display, as [`CharGridCommand`]s or, if `cp437` is set, as [`crate::Cp437GridCommand`]s.
+///
+/// The brightness of each tile follows the [`Modifier::BOLD`] and [`Modifier::DIM`] modifiers of its cell.
+/// Colors are ignored, as the display can only show one color.
+///
+/// # Examples
+///
+/// ```rust
+/// # use servicepoint::*;
+/// use ratatui_core::{terminal::Terminal, text::Line};
+///
+/// let backend = DisplayBackend::new(FakeConnection);
+/// let mut terminal = Terminal::new(backend).unwrap();
+/// terminal
+///     .draw(|frame| frame.render_widget(Line::from("Hello"), frame.area()))
+///     .unwrap();
+/// ```
+#[derive(Debug)]
+pub struct DisplayBackend<C: Connection> {
+    connection: C,
+    text: CharGrid,
+    brightness: BrightnessGrid,
+    sent: Option<(CharGrid, BrightnessGrid)>,
+    cursor: Position,
+    /// brightness of cells without [`Modifier::BOLD`] or [`Modifier::DIM`]
+    pub normal: Brightness,
+    /// brightness of cells with [`Modifier::BOLD`]
+    pub bold: Brightness,
+    /// brightness of cells with [`Modifier::DIM`]
+    pub dim: Brightness,
+    /// send the text as CP-437 instead of UTF-8, e.g. for older firmware
+    #[cfg(feature = "cp437")]
+    pub cp437: bool,
+}
+
+impl<C: Connection> DisplayBackend<C> {
+    /// Creates a backend with an empty screen, sending to the provided connection.
+    ///
+    /// Normal text is shown at brightness 8, bold text at [`Brightness::MAX`] and dim text at 4.
+    /// The first flush sends everything.
+    #[must_use]
+    pub fn new(connection: C) -> Self {
+        let normal = Brightness::saturating_from(8);
+        let mut text = CharGrid::new(TILE_WIDTH, TILE_HEIGHT);
+        text.fill(' ');
+        let mut brightness = BrightnessGrid::new(TILE_WIDTH, TILE_HEIGHT);
+        brightness.fill(normal);
+        Self {
+            connection,
+            text,
+            brightness,
+            sent: None,
+            cursor: Position::ORIGIN,
+            normal,
+            bold: Brightness::MAX,
+            dim: Brightness::saturating_from(4),
+            #[cfg(feature = "cp437")]
+            cp437: false,
+        }
+    }
+
+    /// The connection the changes are sent to.
+    #[must_use]
+    pub fn connection(&self) -> &C {
+        &self.connection
+    }
+
+    /// The text as it will be shown after the next flush.
+    #[must_use]
+    pub fn text(&self) -> &CharGrid {
+        &self.text
+    }
+
+    /// The brightness as it will be shown after the next flush.
+    #[must_use]
+    pub fn brightness(&self) -> &BrightnessGrid {
+        &self.brightness
+    }
+
+    /// Makes the next flush send everything, e.g. after the display has been reset.
+    pub fn invalidate(&mut self) {
+        self.sent = None;
+    }
+
+    fn cell_brightness(&self, cell: &Cell) -> Brightness {
+        if cell.modifier.contains(Modifier::BOLD) {
+            self.bold
+        } else if cell.modifier.contains(Modifier::DIM) {
+            self.dim
+        } else {
+            self.normal
+        }
+    }
+
+    fn clear_cells(&mut self, y: usize, xs: Range<usize>) {
+        for x in xs {
+            self.text.set(x, y, ' ');
+            self.brightness.set(x, y, self.normal);
+        }
+    }
+
+    fn clear_rows(&mut self, ys: Range<usize>) {
+        for y in ys {
+            self.clear_cells(y, 0..self.text.width());
+        }
+    }
+
+    fn packets(&self) -> Option<Vec<Packet>> {
+        let everything = |width, height| {
+            let mut diff = GridDiff::<Tiles>::unchanged(width, height);
+            diff.mark_all();
+            diff
+        };
+        let sent = self.sent.as_ref();
+        let text_changed = sent
+            .and_then(|(text, _)| GridDiff::value_grids(text, &self.text))
+            .unwrap_or_else(|| {
+                everything(self.text.width(), self.text.height())
+            });
+        let brightness_changed = sent
+            .and_then(|(_, brightness)| {
+                GridDiff::value_grids(brightness, &self.brightness)
+            })
+            .unwrap_or_else(|| {
+                everything(self.brightness.width(), self.brightness.height())
+            });
+
+        let mut packets = Vec::new();
+        for region in text_changed.exact_regions() {
+            let text = self.text.extract(region.xs(), region.ys()).ok()?;
+            let origin = region.origin;
+            #[cfg(feature = "cp437")]
+            if self.cp437 {
+                let grid = crate::Cp437Grid::from(&text);
+                let command = crate::Cp437GridCommand { grid, origin };
+                packets.push(Packet::try_from(command).ok()?);
+                continue;
+            }
+            let command = CharGridCommand { grid: text, origin };
+            packets.push(Packet::try_from(command).ok()?);
+        }
+        for region in brightness_changed.exact_regions() {
+            let grid =
+                self.brightness.extract(region.xs(), region.ys()).ok()?;
+            let command = BrightnessGridCommand {
+                grid,
+                origin: region.origin,
+            };
+            packets.push(Packet::try_from(command).ok()?);
+        }
+        Some(packets)
+    }
+}
+
+impl<C: Connection> Backend for DisplayBackend<C> {
+    type Error = FlushFailedError;
+
+    fn draw<'a, I>(&mut self, content: I) -> Result<(), Self::Error>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        for (x, y, cell) in content {
+            let (x, y) = (usize::from(x), usize::from(y));
+            let char = cell.symbol().chars().next().unwrap_or(' ');
+            let brightness = self.cell_brightness(cell);
+            if self.text.set_optional(x, y, char) {
+                self.brightness.set(x, y, brightness);
+            }
+        }
+        Ok(())
+    }
+
+    fn hide_cursor(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn show_cursor(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn get_cursor_position(&mut self) -> Result<Position, Self::Error> {
+        Ok(self.cursor)
+    }
+
+    fn set_cursor_position<P: Into<Position>>(
+        &mut self,
+        position: P,
+    ) -> Result<(), Self::Error> {
+        self.cursor = position.into();
+        Ok(())
+    }
+
+    fn clear(&mut self) -> Result<(), Self::Error> {
+        self.clear_region(ClearType::All)
+    }
+
+    fn clear_region(
+        &mut self,
+        clear_type: ClearType,
+    ) -> Result<(), Self::Error> {
+        let (x, y) = (usize::from(self.cursor.x), usize::from(self.cursor.y));
+        let (width, height) = (self.text.width(), self.text.height());
+        let y = y.min(height.saturating_sub(1));
+        let rest_of_line = x.min(width)..width;
+        let line_start = 0..(x + 1).min(width);
+        match clear_type {
+            ClearType::All => self.clear_rows(0..height),
+            ClearType::AfterCursor => {
+                self.clear_rows(y + 1..height);
+                self.clear_cells(y, rest_of_line);
+            }
+            ClearType::BeforeCursor => {
+                self.clear_rows(0..y);
+                self.clear_cells(y, line_start);
+            }
+            ClearType::CurrentLine => self.clear_rows(y..y + 1),
+            ClearType::UntilNewLine => self.clear_cells(y, rest_of_line),
+        }
+        Ok(())
+    }
+
+    #[allow(
+        clippy::cast_possible_truncation,
+        reason = "the display size fits into u16"
+    )]
+    fn size(&self) -> Result<Size, Self::Error> {
+        Ok(Size::new(TILE_WIDTH as u16, TILE_HEIGHT as u16))
+    }
+
+    #[allow(
+        clippy::cast_possible_truncation,
+        reason = "the display size fits into u16"
+    )]
+    fn window_size(&mut self) -> Result<WindowSize, Self::Error> {
+        Ok(WindowSize {
+            columns_rows: self.size()?,
+            pixels: Size::new(PIXEL_WIDTH as u16, PIXEL_HEIGHT as u16),
+        })
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        let packets = self.packets();
+        self.sent = None;
+        for packet in packets.ok_or(FlushFailedError)? {
+            self.connection
+                .send_packet(packet)
+                .ok_or(FlushFailedError)?;
+        }
+        self.sent = Some((self.text.clone(), self.brightness.clone()));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        connection::{FailingConnection, RecordingConnection},
+        Brightness, CharGridExt, DisplayBackend, FakeConnection,
+        FlushFailedError, Grid,
+    };
+    use ratatui_core::{
+        backend::{Backend, ClearType},
+        buffer::Cell,
+        style::{Modifier, Style},
+    };
+
+    fn styled(symbol: &'static str, modifier: Modifier) -> Cell {
+        let mut cell = Cell::new(symbol);
+        cell.set_style(Style::new().add_modifier(modifier));
+        cell
+    }
+
+    #[test]
+    fn draw_with_modifiers() {
+        let mut backend = DisplayBackend::new(FakeConnection);
+        let cells = [
+            Cell::new("a"),
+            styled("b", Modifier::BOLD),
+            styled("c", Modifier::DIM),
+        ];
+        backend
+            .draw(
+                cells
+                    .iter()
+                    .enumerate()
+                    .map(|(x, cell)| (x as u16, 1, cell)),
+            )
+            .unwrap();
+        // out of bounds cells are skipped
+        backend.draw([(500, 500, &cells[0])].into_iter()).unwrap();
+
+        assert_eq!(backend.text().get_row_str(1).unwrap()[..3], *"abc");
+        assert_eq!(backend.brightness().get(0, 1), backend.normal);
+        assert_eq!(backend.brightness().get(1, 1), Brightness::MAX);
+        assert_eq!(backend.brightness().get(2, 1), backend.dim);
+    }
+
+    #[test]
+    fn flush_sends_changes() {
+        let mut backend = DisplayBackend::new(RecordingConnection::default());
+        backend.flush().unwrap();
+        assert_eq!(backend.connection().packets().len(), 2);
+        backend.flush().unwrap();
+        assert_eq!(backend.connection().packets().len(), 2);
+
+        let cell = styled("x", Modifier::BOLD);
+        backend.draw([(3, 4, &cell)].into_iter()).unwrap();
+        backend.flush().unwrap();
+        assert_eq!(backend.connection().packets().len(), 4);
+
+        backend.invalidate();
+        backend.flush().unwrap();
+        assert_eq!(backend.connection().packets().len(), 6);
+
+        let mut backend = DisplayBackend::new(FailingConnection);
+        assert_eq!(backend.flush(), Err(FlushFailedError));
+    }
+
+    #[test]
+    fn flush_sends_only_changed_cells() {
+        let mut backend = DisplayBackend::new(RecordingConnection::default());
+        backend.flush().unwrap();
+
+        let cell = styled("x", Modifier::BOLD);
+        backend
+            .draw([(1, 1, &cell), (2, 2, &cell)].into_iter())
+            .unwrap();
+        backend.flush().unwrap();
+        // after the two initial packets,
+        // diagonal neighbors are sent separately, for text and brightness alike
+        let packets = backend.connection().packets();
+        assert_eq!(packets.len(), 6);
+        for packet in &packets[4..] {
+            let command =
+                crate::BrightnessGridCommand::try_from(packet.clone()).unwrap();
+            assert_eq!((command.grid.width(), command.grid.height()), (1, 1));
+        }
+    }
+
+    #[test]
+    fn clear_region() {
+        let mut backend = DisplayBackend::new(FakeConnection);
+        let cell = &Cell::new("#");
+        let width = backend.text().width() as u16;
+        let all = (0..2).flat_map(|y| (0..width).map(move |x| (x, y, cell)));
+        backend.draw(all.clone()).unwrap();
+
+        backend.set_cursor_position((2, 0)).unwrap();
+        backend.clear_region(ClearType::UntilNewLine).unwrap();
+        assert_eq!(backend.text().get_row_str(0).unwrap()[..4], *"##  ");
+        backend.clear_region(ClearType::BeforeCursor).unwrap();
+        assert_eq!(backend.text().get_row_str(0).unwrap().trim(), "");
+        assert!(backend.text().get_row_str(1).unwrap().starts_with("###"));
+
+        backend.set_cursor_position((0, 1)).unwrap();
+        backend.clear_region(ClearType::CurrentLine).unwrap();
+        assert_eq!(backend.text().get_row_str(1).unwrap().trim(), "");
+
+        backend.draw(all).unwrap();
+        backend.clear().unwrap();
+        assert!(backend.text().get_row_str(0).unwrap().trim().is_empty());
+    }
+
+    #[cfg(feature = "cp437")]
+    #[test]
+    fn cp437() {
+        let mut backend = DisplayBackend::new(RecordingConnection::default());
+        backend.cp437 = true;
+        backend.flush().unwrap();
+        let packets = backend.connection().packets();
+        assert!(crate::Cp437GridCommand::try_from(packets[0].clone()).is_ok());
+    }
+}
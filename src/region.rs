@@ -0,0 +1,112 @@
+use crate::{origin::DisplayUnit, Origin, Pixels, Tiles, TILE_SIZE};
+use std::ops::Range;
+
+/// A rectangular area of the display, e.g. the part that changed since the last update.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Region<Unit: DisplayUnit> {
+    /// the top left corner
+    pub origin: Origin<Unit>,
+    /// size in the width direction
+    pub width: usize,
+    /// size in the height direction
+    pub height: usize,
+}
+
+impl<Unit: DisplayUnit> Region<Unit> {
+    /// Create a new [Region] instance for the provided position and size.
+    #[must_use]
+    pub fn new(origin: Origin<Unit>, width: usize, height: usize) -> Self {
+        Self {
+            origin,
+            width,
+            height,
+        }
+    }
+
+    /// The columns covered by this region, e.g. for creating a [`crate::Window`].
+    #[must_use]
+    pub fn xs(&self) -> Range<usize> {
+        self.origin.x..self.origin.x + self.width
+    }
+
+    /// The rows covered by this region, e.g. for creating a [`crate::Window`].
+    #[must_use]
+    pub fn ys(&self) -> Range<usize> {
+        self.origin.y..self.origin.y + self.height
+    }
+
+    /// Checks whether the region covers no area at all.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// Checks whether the provided position is inside of the region.
+    #[must_use]
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        self.xs().contains(&x) && self.ys().contains(&y)
+    }
+
+    /// The smallest region containing both regions.
+    ///
+    /// Empty regions are ignored.
+    #[must_use]
+    pub fn union(self, other: Self) -> Self {
+        if other.is_empty() {
+            return self;
+        }
+        if self.is_empty() {
+            return other;
+        }
+        let x = self.origin.x.min(other.origin.x);
+        let y = self.origin.y.min(other.origin.y);
+        let right = self.xs().end.max(other.xs().end);
+        let bottom = self.ys().end.max(other.ys().end);
+        Self::new(Origin::new(x, y), right - x, bottom - y)
+    }
+}
+
+impl From<&Region<Tiles>> for Region<Pixels> {
+    fn from(value: &Region<Tiles>) -> Self {
+        Self {
+            origin: Origin::from(&value.origin),
+            width: value.width * TILE_SIZE,
+            height: value.height * TILE_SIZE,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Origin, Pixels, Region, Tiles};
+
+    #[test]
+    fn ranges() {
+        let region = Region::<Pixels>::new(Origin::new(8, 2), 16, 3);
+        assert_eq!(region.xs(), 8..24);
+        assert_eq!(region.ys(), 2..5);
+        assert!(region.contains(8, 4));
+        assert!(!region.contains(24, 4));
+        assert!(!region.is_empty());
+        assert!(Region::<Pixels>::new(Origin::ZERO, 0, 3).is_empty());
+    }
+
+    #[test]
+    fn union() {
+        let a = Region::<Tiles>::new(Origin::new(1, 1), 2, 2);
+        let b = Region::new(Origin::new(4, 0), 1, 1);
+        assert_eq!(a.union(b), Region::new(Origin::new(1, 0), 4, 3));
+        let empty = Region::new(Origin::ZERO, 0, 0);
+        assert_eq!(a.union(empty), a);
+        assert_eq!(empty.union(a), a);
+    }
+
+    #[test]
+    fn tiles_to_pixels() {
+        let tiles = Region::<Tiles>::new(Origin::new(1, 2), 3, 1);
+        assert_eq!(
+            Region::<Pixels>::from(&tiles),
+            Region::new(Origin::new(8, 16), 24, 8)
+        );
+    }
+}
@@ -0,0 +1,473 @@
+use crate::{Bitmap, BoolGridMutExt, Grid, GridMut};
+use std::time::{Duration, Instant};
+
+/// One image of a [Sprite] animation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpriteFrame {
+    /// the pixels of the frame
+    pub bitmap: Bitmap,
+    /// which pixels of `bitmap` are drawn, has to be at least as big as `bitmap`.
+    ///
+    /// When not set, all pixels are drawn.
+    pub mask: Option<Bitmap>,
+    /// how long the frame is shown.
+    ///
+    /// A frame with a duration of zero is shown until the animation is reset.
+    pub duration: Duration,
+}
+
+/// What a [Sprite] does after its last frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LoopMode {
+    /// Stop at the last frame.
+    Once,
+    /// Start over with the first frame.
+    #[default]
+    Loop,
+    /// Play the frames in reverse order, then forward again.
+    PingPong,
+}
+
+/// An animated image with a position and velocity.
+///
+/// Positions are in pixels, velocities are in pixels per second.
+/// Fractional positions are rounded when drawing.
+///
+/// # Examples
+///
+/// ```rust
+/// # use servicepoint::*;
+/// # use std::time::Duration;
+/// # let connection = FakeConnection;
+/// // two 8x8 frames next to each other
+/// let mut sheet = Bitmap::new(16, 8).unwrap();
+/// sheet.window_mut(8..16, 0..8).unwrap().fill(true);
+///
+/// let mut sprite = Sprite::from_sheet(&sheet, None, 8, 8, Duration::from_millis(250)).unwrap();
+/// sprite.velocity_x = 40.0;
+///
+/// let mut frame = Bitmap::max_sized();
+/// for _ in 0..10 {
+///     sprite.advance(FRAME_PACING);
+///     frame.fill(false);
+///     sprite.draw(&mut frame);
+///     connection.send_command(BitmapCommand::from(frame.clone())).unwrap();
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sprite {
+    frames: Vec<SpriteFrame>,
+    /// what happens after the last frame
+    pub loop_mode: LoopMode,
+    /// position of the left edge
+    pub x: f64,
+    /// position of the top edge
+    pub y: f64,
+    /// movement in the width direction, in pixels per second
+    pub velocity_x: f64,
+    /// movement in the height direction, in pixels per second
+    pub velocity_y: f64,
+    current: usize,
+    elapsed: Duration,
+    reverse: bool,
+    finished: bool,
+}
+
+impl Sprite {
+    /// Creates a looping sprite at the top left that does not move.
+    ///
+    /// # Panics
+    ///
+    /// - when `frames` is empty
+    #[must_use]
+    pub fn new(frames: Vec<SpriteFrame>) -> Self {
+        assert!(!frames.is_empty(), "a sprite needs at least one frame");
+        Self {
+            frames,
+            loop_mode: LoopMode::default(),
+            x: 0.0,
+            y: 0.0,
+            velocity_x: 0.0,
+            velocity_y: 0.0,
+            current: 0,
+            elapsed: Duration::ZERO,
+            reverse: false,
+            finished: false,
+        }
+    }
+
+    /// Cuts a sprite sheet into frames of the same size.
+    ///
+    /// Frames are read row by row, from left to right.
+    ///
+    /// # Arguments
+    ///
+    /// - `sheet`: all frames of the animation
+    /// - `mask`: the masks of all frames, laid out the same way as `sheet`
+    /// - `frame_width`, `frame_height`: size of one frame
+    /// - `frame_duration`: how long each frame is shown
+    ///
+    /// returns: None if
+    /// - `frame_width` is not a multiple of 8 or zero
+    /// - `frame_height` is zero
+    /// - the sheet is smaller than one frame
+    /// - the mask is not the same size as the sheet
+    #[must_use]
+    pub fn from_sheet(
+        sheet: &Bitmap,
+        mask: Option<&Bitmap>,
+        frame_width: usize,
+        frame_height: usize,
+        frame_duration: Duration,
+    ) -> Option<Self> {
+        if frame_width == 0 || frame_height == 0 {
+            return None;
+        }
+        if let Some(mask) = mask {
+            if (mask.width(), mask.height()) != (sheet.width(), sheet.height())
+            {
+                return None;
+            }
+        }
+
+        let columns = sheet.width() / frame_width;
+        let rows = sheet.height() / frame_height;
+        let mut frames = Vec::with_capacity(columns * rows);
+        for row in 0..rows {
+            for column in 0..columns {
+                let xs = column * frame_width..(column + 1) * frame_width;
+                let ys = row * frame_height..(row + 1) * frame_height;
+                let cut = |source: &Bitmap| {
+                    let mut bitmap = Bitmap::new(frame_width, frame_height)?;
+                    bitmap.blit(&source.window(xs.clone(), ys.clone())?, 0, 0);
+                    Some(bitmap)
+                };
+                frames.push(SpriteFrame {
+                    bitmap: cut(sheet)?,
+                    mask: match mask {
+                        Some(mask) => Some(cut(mask)?),
+                        None => None,
+                    },
+                    duration: frame_duration,
+                });
+            }
+        }
+
+        if frames.is_empty() {
+            return None;
+        }
+        Some(Self::new(frames))
+    }
+
+    /// All frames of the animation in order.
+    #[must_use]
+    pub fn frames(&self) -> &[SpriteFrame] {
+        &self.frames
+    }
+
+    /// The index of the frame that is currently shown.
+    #[must_use]
+    pub fn current_index(&self) -> usize {
+        self.current
+    }
+
+    /// The frame that is currently shown.
+    #[must_use]
+    pub fn current_frame(&self) -> &SpriteFrame {
+        &self.frames[self.current]
+    }
+
+    /// Checks whether an animation with [`LoopMode::Once`] has reached the end of its last frame.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Starts the animation over from the first frame.
+    pub fn reset(&mut self) {
+        self.current = 0;
+        self.elapsed = Duration::ZERO;
+        self.reverse = false;
+        self.finished = false;
+    }
+
+    /// Moves the sprite and the animation forward by the provided time.
+    pub fn advance(&mut self, elapsed: Duration) {
+        let seconds = elapsed.as_secs_f64();
+        self.x += self.velocity_x * seconds;
+        self.y += self.velocity_y * seconds;
+
+        if self.finished {
+            return;
+        }
+        self.elapsed += elapsed;
+        loop {
+            let duration = self.current_frame().duration;
+            if duration.is_zero() || self.elapsed < duration {
+                return;
+            }
+            self.elapsed -= duration;
+            if !self.step() {
+                self.finished = true;
+                self.elapsed = Duration::ZERO;
+                return;
+            }
+        }
+    }
+
+    /// Moves to the next frame according to the loop mode.
+    ///
+    /// returns: false if the animation is over
+    fn step(&mut self) -> bool {
+        let last = self.frames.len() - 1;
+        match self.loop_mode {
+            LoopMode::Once if self.current == last => return false,
+            LoopMode::Once => self.current += 1,
+            LoopMode::Loop => {
+                self.current = if self.current == last {
+                    0
+                } else {
+                    self.current + 1
+                };
+            }
+            LoopMode::PingPong if last == 0 => {}
+            LoopMode::PingPong => {
+                if self.current == last {
+                    self.reverse = true;
+                } else if self.current == 0 {
+                    self.reverse = false;
+                }
+                if self.reverse {
+                    self.current -= 1;
+                } else {
+                    self.current += 1;
+                }
+            }
+        }
+        true
+    }
+
+    /// The position of the top left corner, rounded to whole pixels.
+    #[must_use]
+    #[allow(
+        clippy::cast_possible_truncation,
+        reason = "positions that far away are not visible anyway"
+    )]
+    pub fn position(&self) -> (isize, isize) {
+        (self.x.round() as isize, self.y.round() as isize)
+    }
+
+    /// Draws the current frame onto the target at the current position.
+    ///
+    /// Only pixels inside of the mask are drawn, the rest of the target is not changed.
+    pub fn draw(&self, target: &mut impl GridMut<bool>) {
+        let frame = self.current_frame();
+        let (x, y) = self.position();
+        match &frame.mask {
+            Some(mask) => target.blit_masked(&frame.bitmap, mask, x, y),
+            None => target.blit(&frame.bitmap, x, y),
+        }
+    }
+}
+
+/// Advances a group of [Sprite]s together.
+///
+/// # Examples
+///
+/// ```rust
+/// # use servicepoint::*;
+/// # use std::time::Duration;
+/// # let connection = FakeConnection;
+/// # let sprite = Sprite::from_sheet(&Bitmap::new(8, 8).unwrap(), None, 8, 8, Duration::from_secs(1)).unwrap();
+/// let mut timeline = Timeline::new();
+/// timeline.push(sprite);
+///
+/// let mut frame = Bitmap::max_sized();
+/// for _ in 0..3 {
+///     std::thread::sleep(FRAME_PACING);
+///     timeline.tick();
+///
+///     frame.fill(false);
+///     timeline.draw(&mut frame);
+///     connection.send_command(BitmapCommand::from(frame.clone())).unwrap();
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Timeline {
+    sprites: Vec<Sprite>,
+    last_tick: Option<Instant>,
+}
+
+impl Timeline {
+    /// Creates a timeline without sprites.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a sprite, which is drawn on top of all other sprites.
+    ///
+    /// returns: the index of the new sprite
+    pub fn push(&mut self, sprite: Sprite) -> usize {
+        self.sprites.push(sprite);
+        self.sprites.len() - 1
+    }
+
+    /// Removes the sprite at the specified index.
+    ///
+    /// returns: None if there is no sprite at the index
+    pub fn remove(&mut self, index: usize) -> Option<Sprite> {
+        if index < self.sprites.len() {
+            Some(self.sprites.remove(index))
+        } else {
+            None
+        }
+    }
+
+    /// All sprites, in drawing order.
+    #[must_use]
+    pub fn sprites(&self) -> &[Sprite] {
+        &self.sprites
+    }
+
+    /// All sprites for modification, in drawing order.
+    #[must_use]
+    pub fn sprites_mut(&mut self) -> &mut [Sprite] {
+        &mut self.sprites
+    }
+
+    /// Moves all sprites forward by the provided time.
+    pub fn advance(&mut self, elapsed: Duration) {
+        for sprite in &mut self.sprites {
+            sprite.advance(elapsed);
+        }
+    }
+
+    /// Moves all sprites forward by the time passed since the last call.
+    ///
+    /// The first call only starts measuring.
+    pub fn tick(&mut self) {
+        let now = Instant::now();
+        if let Some(last_tick) = self.last_tick {
+            self.advance(now - last_tick);
+        }
+        self.last_tick = Some(now);
+    }
+
+    /// Draws all sprites onto the target in order.
+    pub fn draw(&self, target: &mut impl GridMut<bool>) {
+        for sprite in &self.sprites {
+            sprite.draw(target);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Bitmap, DataRef, GridMut, LoopMode, Sprite, SpriteFrame, Timeline,
+    };
+    use std::time::Duration;
+
+    const STEP: Duration = Duration::from_millis(100);
+
+    fn numbered_frames(count: usize) -> Vec<SpriteFrame> {
+        (0..count)
+            .map(|index| SpriteFrame {
+                bitmap: Bitmap::load(8, 1, &[index as u8]).unwrap(),
+                mask: None,
+                duration: STEP,
+            })
+            .collect()
+    }
+
+    fn indices(sprite: &mut Sprite, steps: usize) -> Vec<usize> {
+        (0..steps)
+            .map(|_| {
+                sprite.advance(STEP);
+                sprite.current_index()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn from_sheet() {
+        let mut sheet = Bitmap::new(16, 4).unwrap();
+        sheet.set(8, 0, true);
+        sheet.set(0, 2, true);
+        let mut mask = sheet.clone();
+        mask.fill(true);
+
+        let sprite =
+            Sprite::from_sheet(&sheet, Some(&mask), 8, 2, STEP).unwrap();
+        let frames: Vec<_> = sprite
+            .frames()
+            .iter()
+            .map(|frame| frame.bitmap.data_ref().to_vec())
+            .collect();
+        assert_eq!(frames, [[0, 0], [0x80, 0], [0x80, 0], [0, 0]]);
+        assert!(sprite.frames().iter().all(|frame| frame.mask.is_some()));
+
+        assert!(Sprite::from_sheet(&sheet, None, 4, 2, STEP).is_none());
+        assert!(Sprite::from_sheet(&sheet, None, 8, 5, STEP).is_none());
+        let small_mask = Bitmap::new(8, 4).unwrap();
+        assert!(
+            Sprite::from_sheet(&sheet, Some(&small_mask), 8, 2, STEP).is_none()
+        );
+    }
+
+    #[test]
+    fn loop_modes() {
+        let mut sprite = Sprite::new(numbered_frames(3));
+        assert_eq!(indices(&mut sprite, 4), [1, 2, 0, 1]);
+
+        sprite.reset();
+        sprite.loop_mode = LoopMode::PingPong;
+        assert_eq!(indices(&mut sprite, 6), [1, 2, 1, 0, 1, 2]);
+
+        sprite.reset();
+        sprite.loop_mode = LoopMode::Once;
+        assert_eq!(indices(&mut sprite, 2), [1, 2]);
+        assert!(!sprite.is_finished());
+        assert_eq!(indices(&mut sprite, 2), [2, 2]);
+        assert!(sprite.is_finished());
+    }
+
+    #[test]
+    fn frame_durations() {
+        let mut frames = numbered_frames(3);
+        frames[1].duration = STEP * 3;
+        let mut sprite = Sprite::new(frames);
+        assert_eq!(indices(&mut sprite, 5), [1, 1, 1, 2, 0]);
+
+        // one full cycle takes 5 steps
+        sprite.advance(STEP * 7 + STEP / 2);
+        assert_eq!(sprite.current_index(), 1);
+    }
+
+    #[test]
+    fn movement_and_drawing() {
+        let mut mask = Bitmap::new(8, 1).unwrap();
+        mask.set(1, 0, true);
+        let mut frame = Bitmap::new(8, 1).unwrap();
+        frame.fill(true);
+        let mut sprite = Sprite::new(vec![SpriteFrame {
+            bitmap: frame,
+            mask: Some(mask),
+            duration: Duration::ZERO,
+        }]);
+        sprite.velocity_x = 20.0;
+        sprite.velocity_y = -5.0;
+        sprite.y = 1.0;
+
+        let mut timeline = Timeline::new();
+        let index = timeline.push(sprite);
+        timeline.advance(STEP * 2);
+        assert_eq!(timeline.sprites()[index].position(), (4, 0));
+
+        let mut target = Bitmap::new(16, 2).unwrap();
+        target.set(4, 0, true);
+        timeline.draw(&mut target);
+        assert_eq!(target.data_ref(), [0b0000_1100, 0, 0, 0]);
+    }
+}
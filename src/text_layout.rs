@@ -0,0 +1,410 @@
+use crate::{
+    containers::{glyphs, row_cells, text_width, Glyph},
+    CharGrid, GridMut,
+};
+
+/// Where the text is placed within a row of a [`TextLayout`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HorizontalAlignment {
+    /// start each line at the left edge
+    #[default]
+    Left,
+    /// place each line in the middle, rounding to the left
+    Center,
+    /// end each line at the right edge
+    Right,
+    /// stretch the spaces between words so each line reaches both edges.
+    ///
+    /// The last line of each paragraph is aligned to the left.
+    Justify,
+}
+
+/// Where the lines are placed within the height of a [`TextLayout`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VerticalAlignment {
+    /// start at the top row
+    #[default]
+    Top,
+    /// place the lines in the middle, rounding to the top
+    Middle,
+    /// end at the bottom row
+    Bottom,
+}
+
+/// Lays out text in a fixed width, breaking lines between words.
+///
+/// Lines are broken at whitespace. Words that do not fit into a line on their own
+/// are split, marking the break with [`Self::hyphen`].
+/// Line breaks in the text start a new paragraph.
+///
+/// When there are more lines than [`Self::max_lines`] or [`Self::height`] allow,
+/// the remaining lines are dropped and the last shown line ends with [`Self::ellipsis`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use servicepoint::*;
+/// let mut layout = TextLayout::new(10);
+/// layout.horizontal = HorizontalAlignment::Center;
+/// layout.max_lines = Some(2);
+/// layout.ellipsis = Some("...".to_owned());
+///
+/// let grid = layout.render("The quick brown fox jumps over the lazy dog");
+/// assert_eq!(String::from(grid), "The quick \nbrown f...");
+///
+/// # let connection = FakeConnection;
+/// # let grid = layout.render("Hello");
+/// connection.send_command(CharGridCommand { origin: Origin::ZERO, grid }).unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextLayout {
+    /// the amount of characters per line
+    pub width: usize,
+    /// the exact amount of rows of the result, if set.
+    ///
+    /// Unused rows are filled with spaces. When not set, there are as many rows as lines.
+    pub height: Option<usize>,
+    /// the maximum amount of lines, if set
+    pub max_lines: Option<usize>,
+    /// alignment of each line
+    pub horizontal: HorizontalAlignment,
+    /// alignment of the lines if [`Self::height`] is set
+    pub vertical: VerticalAlignment,
+    /// appended to the last shown line if the text did not fit
+    pub ellipsis: Option<String>,
+    /// marks where a word had to be split. Words are split without a mark if not set.
+    pub hyphen: Option<char>,
+}
+
+/// A line of words, before alignment.
+#[derive(Debug)]
+struct Line {
+    words: Vec<String>,
+    /// the last line of a paragraph is never justified
+    ends_paragraph: bool,
+}
+
+impl Line {
+    fn new() -> Self {
+        Self {
+            words: Vec::new(),
+            ends_paragraph: false,
+        }
+    }
+
+    /// The amount of characters with single spaces between the words.
+    fn len(&self) -> usize {
+        let widths = self.words.iter().map(|word| text_width(word));
+        widths.sum::<usize>() + self.words.len().saturating_sub(1)
+    }
+}
+
+fn width_of(glyphs: &[Glyph]) -> usize {
+    glyphs.iter().map(|glyph| glyph.width).sum()
+}
+
+/// The amount of glyphs from the start that fit into `width` cells.
+fn fitting(glyphs: &[Glyph], width: usize) -> usize {
+    let mut used = 0;
+    glyphs
+        .iter()
+        .take_while(|glyph| {
+            used += glyph.width;
+            used <= width
+        })
+        .count()
+}
+
+fn chars_of(glyphs: impl IntoIterator<Item = Glyph>) -> String {
+    glyphs.into_iter().map(|glyph| glyph.char).collect()
+}
+
+impl TextLayout {
+    /// Creates a layout with the specified width, aligning to the top left without any limits.
+    ///
+    /// Split words are marked with '-'.
+    #[must_use]
+    pub fn new(width: usize) -> Self {
+        Self {
+            width,
+            height: None,
+            max_lines: None,
+            horizontal: HorizontalAlignment::default(),
+            vertical: VerticalAlignment::default(),
+            ellipsis: None,
+            hyphen: Some('-'),
+        }
+    }
+
+    /// Lays out the text into a grid of [`Self::width`] columns.
+    ///
+    /// All cells not covered by text are spaces.
+    #[must_use]
+    pub fn render(&self, text: &str) -> CharGrid {
+        let rows = self.rows(text);
+        let mut grid = CharGrid::new(self.width, rows.len());
+        for (y, row) in rows.iter().enumerate() {
+            for (x, char) in
+                row_cells(row).into_iter().take(self.width).enumerate()
+            {
+                grid.set(x, y, char);
+            }
+        }
+        grid
+    }
+
+    /// Like [`Self::render`], converting the result to CP-437.
+    ///
+    /// Characters that are not available are replaced.
+    #[cfg(feature = "cp437")]
+    #[must_use]
+    pub fn render_cp437(&self, text: &str) -> crate::Cp437Grid {
+        crate::Cp437Grid::from(self.render(text))
+    }
+
+    /// Lays out the text into rows of exactly [`Self::width`] cells.
+    ///
+    /// Wide characters like CJK ideographs take up two cells, see [`crate::CharGridMutExt::set_row_str`].
+    #[must_use]
+    pub fn rows(&self, text: &str) -> Vec<String> {
+        let mut lines = if self.width == 0 {
+            Vec::new()
+        } else {
+            self.wrap(text)
+        };
+
+        let limit = match (self.max_lines, self.height) {
+            (Some(max_lines), Some(height)) => Some(max_lines.min(height)),
+            (limit, None) | (None, limit) => limit,
+        };
+        let mut truncated = None;
+        if let Some(limit) = limit {
+            if lines.len() > limit {
+                lines.truncate(limit);
+                truncated = lines.pop().map(|line| self.truncate(&line));
+            }
+        }
+
+        let mut rows = lines
+            .iter()
+            .map(|line| self.align(line))
+            .collect::<Vec<_>>();
+        rows.extend(truncated);
+
+        if let Some(height) = self.height {
+            let free = height - rows.len();
+            let above = match self.vertical {
+                VerticalAlignment::Top => 0,
+                VerticalAlignment::Middle => free / 2,
+                VerticalAlignment::Bottom => free,
+            };
+            let empty = " ".repeat(self.width);
+            rows.splice(0..0, std::iter::repeat(empty.clone()).take(above));
+            rows.resize(height, empty);
+        }
+        rows
+    }
+
+    /// Breaks the text into lines of at most [`Self::width`] characters.
+    fn wrap(&self, text: &str) -> Vec<Line> {
+        let mut lines = Vec::new();
+        for paragraph in text.lines() {
+            let mut line = Line::new();
+            for word in paragraph.split_whitespace() {
+                let word_len = text_width(word);
+                if !line.words.is_empty()
+                    && line.len() + 1 + word_len > self.width
+                {
+                    lines.push(std::mem::replace(&mut line, Line::new()));
+                }
+                if word_len <= self.width {
+                    line.words.push(word.to_owned());
+                    continue;
+                }
+
+                if !line.words.is_empty() {
+                    lines.push(std::mem::replace(&mut line, Line::new()));
+                }
+                let mut rest = glyphs(word);
+                while width_of(&rest) > self.width {
+                    let hyphen = self.hyphen.filter(|_| self.width > 1);
+                    let part = self.width - usize::from(hyphen.is_some());
+                    let count = fitting(&rest, part);
+                    let mut piece = chars_of(rest.drain(..count.max(1)));
+                    if count > 0 {
+                        piece.extend(hyphen);
+                    }
+                    lines.push(Line {
+                        words: vec![piece],
+                        ends_paragraph: false,
+                    });
+                }
+                if !rest.is_empty() {
+                    line.words.push(chars_of(rest));
+                }
+            }
+            line.ends_paragraph = true;
+            lines.push(line);
+        }
+        lines
+    }
+
+    /// Shortens the line so it fits together with the ellipsis.
+    fn truncate(&self, line: &Line) -> String {
+        let mut text = line.words.join(" ");
+        let Some(ellipsis) = &self.ellipsis else {
+            return self.align(line);
+        };
+
+        let ellipsis = glyphs(ellipsis);
+        let ellipsis = &ellipsis[..fitting(&ellipsis, self.width)];
+        let space = self.width - width_of(ellipsis);
+        let glyphs = glyphs(&text);
+        text = chars_of(glyphs[..fitting(&glyphs, space)].iter().copied());
+        text.truncate(text.trim_end().len());
+        text.push_str(&chars_of(ellipsis.iter().copied()));
+        self.align(&Line {
+            words: vec![text],
+            ends_paragraph: true,
+        })
+    }
+
+    /// Pads the line to [`Self::width`] according to [`Self::horizontal`].
+    fn align(&self, line: &Line) -> String {
+        let free = self.width.saturating_sub(line.len());
+        let gaps = line.words.len().saturating_sub(1);
+        if self.horizontal == HorizontalAlignment::Justify
+            && !line.ends_paragraph
+            && gaps > 0
+        {
+            let mut result = String::with_capacity(self.width);
+            for (index, word) in line.words.iter().enumerate() {
+                if index > 0 {
+                    // the leftmost gaps get the remainder
+                    let extra = free / gaps + usize::from(index <= free % gaps);
+                    result.push_str(&" ".repeat(1 + extra));
+                }
+                result.push_str(word);
+            }
+            return result;
+        }
+
+        let before = match self.horizontal {
+            HorizontalAlignment::Left | HorizontalAlignment::Justify => 0,
+            HorizontalAlignment::Center => free / 2,
+            HorizontalAlignment::Right => free,
+        };
+        format!(
+            "{}{}{}",
+            " ".repeat(before),
+            line.words.join(" "),
+            " ".repeat(free - before)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{HorizontalAlignment, TextLayout, VerticalAlignment};
+
+    #[test]
+    fn wraps_words() {
+        let layout = TextLayout::new(7);
+        assert_eq!(
+            layout.rows("Hello dear  World\n\nbye"),
+            ["Hello  ", "dear   ", "World  ", "       ", "bye    "]
+        );
+        assert!(layout.rows("").is_empty());
+    }
+
+    #[test]
+    fn splits_long_words() {
+        let mut layout = TextLayout::new(4);
+        assert_eq!(
+            layout.rows("a servicepoint"),
+            ["a   ", "ser-", "vic-", "epo-", "int "]
+        );
+        layout.hyphen = None;
+        assert_eq!(layout.rows("abcdefghi"), ["abcd", "efgh", "i   "]);
+
+        let mut layout = TextLayout::new(1);
+        assert_eq!(layout.rows("abc"), ["a", "b", "c"]);
+        layout.width = 0;
+        assert!(layout.rows("abc").is_empty());
+    }
+
+    #[test]
+    fn horizontal_alignment() {
+        let mut layout = TextLayout::new(12);
+        let text = "one two three four";
+        layout.horizontal = HorizontalAlignment::Center;
+        assert_eq!(layout.rows(text), ["  one two   ", " three four "]);
+        layout.horizontal = HorizontalAlignment::Right;
+        assert_eq!(layout.rows(text), ["     one two", "  three four"]);
+
+        layout.horizontal = HorizontalAlignment::Justify;
+        layout.width = 14;
+        assert_eq!(
+            layout.rows("a bb ccc dd eeee f\nlast line"),
+            ["a  bb  ccc  dd", "eeee f        ", "last line     "]
+        );
+    }
+
+    #[test]
+    fn vertical_alignment() {
+        let mut layout = TextLayout::new(3);
+        layout.height = Some(4);
+        layout.vertical = VerticalAlignment::Middle;
+        assert_eq!(layout.rows("a b"), ["   ", "a b", "   ", "   "]);
+        layout.vertical = VerticalAlignment::Bottom;
+        assert_eq!(layout.rows("a b"), ["   ", "   ", "   ", "a b"]);
+    }
+
+    #[test]
+    fn truncation() {
+        let mut layout = TextLayout::new(8);
+        layout.max_lines = Some(1);
+        assert_eq!(layout.rows("ab cd ef gh"), ["ab cd ef"]);
+
+        layout.ellipsis = Some("...".to_owned());
+        assert_eq!(layout.rows("ab cd ef gh"), ["ab cd..."]);
+        assert_eq!(layout.rows("abc defgh ij"), ["abc...  "]);
+        assert_eq!(layout.rows("abc defg"), ["abc defg"]);
+
+        layout.horizontal = HorizontalAlignment::Right;
+        layout.height = Some(2);
+        layout.max_lines = None;
+        assert_eq!(layout.rows("ab\ncd\nef"), ["      ab", "   cd..."]);
+
+        layout.width = 2;
+        layout.max_lines = Some(1);
+        assert_eq!(layout.rows("abc def"), ["..", "  "]);
+    }
+
+    #[test]
+    fn render() {
+        let mut layout = TextLayout::new(5);
+        layout.height = Some(2);
+        let grid = layout.render("hi");
+        assert_eq!(String::from(grid), "hi   \n     ");
+    }
+
+    #[test]
+    fn wide_chars() {
+        let mut layout = TextLayout::new(5);
+        layout.horizontal = HorizontalAlignment::Right;
+        assert_eq!(layout.rows("中文 abc"), [" 中文", "  abc"]);
+        assert_eq!(layout.rows("中文字"), ["中文-", "   字"]);
+        assert_eq!(String::from(layout.render("e\u{301}中")), "  é中 ");
+    }
+
+    #[cfg(feature = "cp437")]
+    #[test]
+    fn render_cp437() {
+        use crate::Grid;
+        let layout = TextLayout::new(3);
+        let grid = layout.render_cp437("ä b");
+        assert_eq!(grid.get(0, 0), 0x84);
+        assert_eq!(crate::CharGrid::from(grid), layout.render("ä b"));
+    }
+}
@@ -0,0 +1,293 @@
+use crate::{
+    BinaryOperation, Bitmap, BitmapCommand, CompressionCode, Grid, Origin,
+    Pixels, TILE_SIZE,
+};
+
+/// One step of a [Ticker].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TickerFrame {
+    /// the band of the display with the current part of the text
+    pub command: BitmapCommand,
+    /// true if the text completely scrolled out of view with this frame
+    pub pass_completed: bool,
+}
+
+/// Scrolls a line of text through a band of the display, one pixel column at a time.
+///
+/// The text is rendered once with [`Bitmap::from_text`].
+/// Each pass starts with the text just outside of the right edge of the band.
+/// It moves to the left, pauses for a while once its start reached the left edge,
+/// and continues until it is out of view.
+/// The frame where the band is empty again marks the end of the pass.
+///
+/// The ticker is an iterator over the frames to send.
+/// When not looping, it ends after the first pass.
+///
+/// # Examples
+///
+/// ```rust
+/// # use servicepoint::*;
+/// # let connection = FakeConnection;
+/// let mut ticker = Ticker::new(
+///     "This announcement is way too long to fit on the display at once",
+///     Origin::new(0, 8),
+///     PIXEL_WIDTH,
+///     TILE_SIZE,
+/// )
+/// .unwrap();
+/// ticker.pixels_per_frame = 2;
+/// ticker.pause_frames = 30;
+///
+/// for frame in ticker.take(5) {
+///     connection.send_command(frame.command).unwrap();
+///     std::thread::sleep(FRAME_PACING);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ticker {
+    strip: Bitmap,
+    origin: Origin<Pixels>,
+    width: usize,
+    height: usize,
+    /// how far the text moves with each frame.
+    ///
+    /// Zero is treated as one, as the text would never leave the band otherwise.
+    pub pixels_per_frame: usize,
+    /// how many frames the text stays in place when its start reached the left edge of the band
+    pub pause_frames: usize,
+    /// whether to start over after each pass
+    pub looping: bool,
+    /// compression used for the produced commands
+    pub compression: CompressionCode,
+    /// position of the left edge of the band relative to the start of the text
+    offset: isize,
+    paused: usize,
+    finished: bool,
+}
+
+impl Ticker {
+    /// Creates a looping ticker that moves one pixel per frame without pausing.
+    ///
+    /// The text is vertically centered in the band.
+    ///
+    /// # Arguments
+    ///
+    /// - `text`: the text to show in a single line
+    /// - `origin`: top left corner of the band on the display
+    /// - `width`, `height`: size of the band in pixels
+    ///
+    /// returns: None if `origin.x` or `width` are not a multiple of 8
+    #[must_use]
+    pub fn new(
+        text: &str,
+        origin: Origin<Pixels>,
+        width: usize,
+        height: usize,
+    ) -> Option<Self> {
+        if origin.x % TILE_SIZE != 0 || width % TILE_SIZE != 0 {
+            return None;
+        }
+        Some(Self {
+            strip: Bitmap::from_text(text),
+            origin,
+            width,
+            height,
+            pixels_per_frame: 1,
+            pause_frames: 0,
+            looping: true,
+            compression: CompressionCode::default(),
+            offset: Self::start_offset(width),
+            paused: 0,
+            finished: false,
+        })
+    }
+
+    /// The rendered text.
+    #[must_use]
+    pub fn strip(&self) -> &Bitmap {
+        &self.strip
+    }
+
+    /// Starts the first pass again.
+    pub fn reset(&mut self) {
+        self.offset = Self::start_offset(self.width);
+        self.paused = 0;
+        self.finished = false;
+    }
+
+    #[allow(
+        clippy::cast_possible_wrap,
+        reason = "bitmaps are never larger than isize::MAX"
+    )]
+    fn start_offset(width: usize) -> isize {
+        -(width as isize)
+    }
+
+    #[allow(
+        clippy::cast_possible_wrap,
+        reason = "bitmaps are never larger than isize::MAX"
+    )]
+    fn advance(&mut self) -> bool {
+        if self.offset >= self.strip.width() as isize {
+            self.offset = Self::start_offset(self.width);
+            self.paused = 0;
+            return true;
+        }
+        if self.offset == 0 && self.paused < self.pause_frames {
+            self.paused += 1;
+            return false;
+        }
+        let step = self.pixels_per_frame.max(1) as isize;
+        self.offset = if self.offset < 0 {
+            // stop exactly at the left edge for the pause
+            (self.offset + step).min(0)
+        } else {
+            self.offset + step
+        };
+        false
+    }
+
+    #[allow(
+        clippy::cast_possible_wrap,
+        reason = "bitmaps are never larger than isize::MAX"
+    )]
+    fn render(&self) -> Bitmap {
+        #[allow(
+            clippy::unwrap_used,
+            reason = "the width is checked on construction"
+        )]
+        let mut band = Bitmap::new(self.width, self.height).unwrap();
+        let top = (self.height as isize - TILE_SIZE as isize) / 2;
+        band.blit_bitmap(
+            &self.strip,
+            -self.offset,
+            top,
+            BinaryOperation::Overwrite,
+        );
+        band
+    }
+}
+
+impl Iterator for Ticker {
+    type Item = TickerFrame;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let bitmap = self.render();
+        let pass_completed = self.advance();
+        if pass_completed {
+            self.finished = !self.looping;
+        }
+
+        Some(TickerFrame {
+            command: BitmapCommand {
+                bitmap,
+                origin: self.origin,
+                compression: self.compression,
+            },
+            pass_completed,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Bitmap, DataRef, Grid, Origin, Ticker};
+
+    /// The offset of the text for each frame, empty bands are reported as -8.
+    fn offsets(ticker: &mut Ticker, count: usize) -> Vec<Option<isize>> {
+        let strip = ticker.strip().clone();
+        ticker
+            .take(count)
+            .map(|frame| {
+                let bitmap = frame.command.bitmap;
+                (-8..=strip.width() as isize).find(|offset| {
+                    let mut expected = Bitmap::new(8, 8).unwrap();
+                    expected.blit_bitmap(
+                        &strip,
+                        -offset,
+                        0,
+                        crate::BinaryOperation::Overwrite,
+                    );
+                    expected == bitmap
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn invalid_band() {
+        assert!(Ticker::new("a", Origin::new(4, 0), 8, 8).is_none());
+        assert!(Ticker::new("a", Origin::new(8, 0), 12, 8).is_none());
+    }
+
+    #[test]
+    fn scrolls_in_pauses_and_loops() {
+        let mut ticker = Ticker::new("#", Origin::new(8, 3), 8, 8).unwrap();
+        ticker.pixels_per_frame = 3;
+        ticker.pause_frames = 2;
+
+        let frames: Vec<_> = ticker.clone().take(18).collect();
+        assert!(frames
+            .iter()
+            .all(|frame| frame.command.origin == Origin::new(8, 3)));
+        let completed: Vec<_> = frames
+            .iter()
+            .enumerate()
+            .filter(|(_, frame)| frame.pass_completed)
+            .map(|(index, _)| index)
+            .collect();
+        assert_eq!(completed, [8, 17]);
+
+        // the first and last frame of a pass are empty
+        assert!(frames[0].command.bitmap.data_ref().iter().all(|b| *b == 0));
+        assert!(frames[8].command.bitmap.data_ref().iter().all(|b| *b == 0));
+        assert_eq!(
+            offsets(&mut ticker, 10),
+            [-8, -5, -2, 0, 0, 0, 3, 6, -8, -8].map(Some)
+        );
+    }
+
+    #[test]
+    fn narrow_text_crosses_band() {
+        let mut ticker = Ticker::new("a", Origin::ZERO, 16, 8).unwrap();
+        ticker.pixels_per_frame = 0;
+        let frames: Vec<_> = ticker.take(25).collect();
+        // zero is treated as one pixel per frame
+        assert_eq!(
+            frames.iter().position(|frame| frame.pass_completed),
+            Some(24)
+        );
+        // enters at the right edge
+        let glyph = Bitmap::from_text("a");
+        let right = frames[8].command.bitmap.data_ref();
+        assert_eq!(
+            (0..8).map(|row| right[row * 2 + 1]).collect::<Vec<_>>(),
+            glyph.data_ref()
+        );
+    }
+
+    #[test]
+    fn single_pass() {
+        let mut ticker = Ticker::new("ab", Origin::ZERO, 8, 10).unwrap();
+        ticker.looping = false;
+        ticker.pixels_per_frame = 4;
+
+        let frames: Vec<_> = ticker.by_ref().collect();
+        assert_eq!(frames.len(), 7);
+        assert!(frames.last().unwrap().pass_completed);
+        assert_eq!(frames[0].command.bitmap.height(), 10);
+        // vertically centered, reaching the left edge after two frames
+        let strip = Bitmap::from_text("a");
+        assert_eq!(
+            &frames[2].command.bitmap.data_ref()[1..9],
+            strip.data_ref()
+        );
+        assert!(ticker.next().is_none());
+
+        ticker.reset();
+        assert!(ticker.next().is_some());
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{
+    widgets::{put, put_str},
+    GridMut, Origin, Region, Tiles, Widget,
+};
+
+/// The lines used for drawing a [Frame].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LineStyle {
+    /// ┌─┐
+    #[default]
+    Single,
+    /// ╔═╗
+    Double,
+}
+
+impl LineStyle {
+    /// top left, top right, bottom left, bottom right, horizontal, vertical
+    const fn chars(self) -> [char; 6] {
+        match self {
+            LineStyle::Single => ['┌', '┐', '└', '┘', '─', '│'],
+            LineStyle::Double => ['╔', '╗', '╚', '╝', '═', '║'],
+        }
+    }
+}
+
+/// A border around the edge of the area, with an optional title in the top line.
+///
+/// The cells inside of the border are not changed.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Frame {
+    /// the lines to use
+    pub style: LineStyle,
+    /// shown in the top line, cut off if too long
+    pub title: Option<String>,
+}
+
+impl Frame {
+    /// Creates a frame without title.
+    #[must_use]
+    pub fn new(style: LineStyle) -> Self {
+        Self { style, title: None }
+    }
+
+    /// The area inside of the border, e.g. for drawing the content.
+    #[must_use]
+    pub fn inner(&self, area: Region<Tiles>) -> Region<Tiles> {
+        Region::new(
+            Origin::new(area.origin.x + 1, area.origin.y + 1),
+            area.width.saturating_sub(2),
+            area.height.saturating_sub(2),
+        )
+    }
+}
+
+impl Widget for Frame {
+    fn draw(&self, grid: &mut dyn GridMut<char>, area: Region<Tiles>) {
+        if area.width < 2 || area.height < 2 {
+            return;
+        }
+
+        let [top_left, top_right, bottom_left, bottom_right, horizontal, vertical] =
+            self.style.chars();
+        let (right, bottom) = (area.width - 1, area.height - 1);
+        for x in 1..right {
+            put(grid, area, x, 0, horizontal);
+            put(grid, area, x, bottom, horizontal);
+        }
+        for y in 1..bottom {
+            put(grid, area, 0, y, vertical);
+            put(grid, area, right, y, vertical);
+        }
+        put(grid, area, 0, 0, top_left);
+        put(grid, area, right, 0, top_right);
+        put(grid, area, 0, bottom, bottom_left);
+        put(grid, area, right, bottom, bottom_right);
+
+        if let Some(title) = &self.title {
+            put_str(grid, area, 1, 0, title, area.width - 2);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        CharGrid, Frame, GridMut, LineStyle, Origin, Region, Widget,
+        TILE_HEIGHT,
+    };
+
+    #[test]
+    fn single() {
+        let mut grid = CharGrid::new(6, 4);
+        grid.fill(' ');
+        let area = Region::new(Origin::new(1, 0), 5, 3);
+        Frame::new(LineStyle::Single).draw(&mut grid, area);
+        assert_eq!(String::from(grid), " ┌───┐\n │   │\n └───┘\n      ");
+    }
+
+    #[test]
+    fn double_with_title() {
+        let mut grid = CharGrid::new(6, 3);
+        grid.fill(' ');
+        let frame = Frame {
+            style: LineStyle::Double,
+            title: Some("Status".to_owned()),
+        };
+        frame.draw(&mut grid, Region::new(Origin::ZERO, 6, 3));
+        assert_eq!(String::from(grid), "╔Stat╗\n║    ║\n╚════╝");
+        assert_eq!(
+            frame.inner(Region::new(Origin::new(2, 3), 6, 3)),
+            Region::new(Origin::new(3, 4), 4, 1)
+        );
+    }
+
+    #[test]
+    fn clipped() {
+        let mut grid = CharGrid::new(3, 2);
+        grid.fill(' ');
+        let area = Region::new(Origin::new(1, 1), 10, TILE_HEIGHT);
+        Frame::new(LineStyle::Single).draw(&mut grid, area);
+        assert_eq!(String::from(grid), "   \n ┌─");
+    }
+
+    #[cfg(feature = "cp437")]
+    #[test]
+    fn cp437() {
+        let mut grid = crate::Cp437Grid::new(2, 2);
+        Frame::new(LineStyle::Single)
+            .draw_cp437(&mut grid, Region::new(Origin::ZERO, 2, 2));
+        assert_eq!(crate::DataRef::data_ref(&grid), [0xDA, 0xBF, 0xC0, 0xD9]);
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{
+    containers::text_width, widgets::put_str, GridMut, Region, Tiles, Widget,
+};
+
+/// Lines of keys and values, with the values aligned in a column.
+///
+/// Entries that do not fit into the area are not shown.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct KeyValueList {
+    /// the keys and values in order
+    pub entries: Vec<(String, String)>,
+    /// shown between key and value
+    pub separator: String,
+}
+
+impl KeyValueList {
+    /// Creates a list with the provided entries, separating keys and values with ": ".
+    #[must_use]
+    pub fn new(
+        entries: impl IntoIterator<Item = (impl Into<String>, impl Into<String>)>,
+    ) -> Self {
+        Self {
+            entries: entries
+                .into_iter()
+                .map(|(key, value)| (key.into(), value.into()))
+                .collect(),
+            separator: ": ".to_owned(),
+        }
+    }
+}
+
+impl Widget for KeyValueList {
+    fn draw(&self, grid: &mut dyn GridMut<char>, area: Region<Tiles>) {
+        let key_width = self
+            .entries
+            .iter()
+            .map(|(key, _)| text_width(key))
+            .max()
+            .unwrap_or(0);
+        for (y, (key, value)) in
+            self.entries.iter().take(area.height).enumerate()
+        {
+            let mut x = put_str(grid, area, 0, y, key, area.width);
+            while x < key_width.min(area.width) {
+                x += put_str(grid, area, x, y, " ", 1);
+            }
+            let rest = area.width - x;
+            x += put_str(grid, area, x, y, &self.separator, rest);
+            let rest = area.width - x;
+            put_str(grid, area, x, y, value, rest);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CharGrid, GridMut, KeyValueList, Origin, Region, Widget};
+
+    #[test]
+    fn aligned_values() {
+        let mut grid = CharGrid::new(9, 3);
+        grid.fill('.');
+        let list = KeyValueList::new([
+            ("CPU", "12%"),
+            ("Memory", "3 GiB"),
+            ("x", "y"),
+        ]);
+        list.draw(&mut grid, Region::new(Origin::ZERO, 9, 2));
+        assert_eq!(String::from(grid), "CPU   : 1\nMemory: 3\n.........");
+    }
+
+    #[test]
+    fn separator() {
+        let mut grid = CharGrid::new(5, 1);
+        grid.fill('.');
+        let mut list = KeyValueList::new([("a", "b")]);
+        list.separator = " = ".to_owned();
+        list.draw(&mut grid, Region::new(Origin::ZERO, 5, 1));
+        assert_eq!(String::from(grid), "a = b");
+    }
+}
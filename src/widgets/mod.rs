@@ -0,0 +1,133 @@
+//! Widgets for text mode dashboards, drawn with CP-437 box and block characters.
+
+mod frame;
+mod key_value;
+mod progress;
+mod table;
+
+pub use frame::*;
+pub use key_value::*;
+pub use progress::*;
+pub use table::*;
+
+use crate::{containers::row_cells, GridMut, Region, Tiles};
+
+/// Something that can be drawn into an area of a text grid.
+///
+/// All characters used by the provided widgets are part of CP-437.
+/// Cells outside of the grid are skipped, so widgets may be partially visible.
+/// The trait is dyn-compatible, so widgets of different types can be stored as `Box<dyn Widget>`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use servicepoint::*;
+/// let mut grid = CharGrid::new(TILE_WIDTH, TILE_HEIGHT);
+/// let area = Region::new(Origin::ZERO, 20, 5);
+///
+/// let mut frame = Frame::new(LineStyle::Double);
+/// frame.title = Some("Status".to_owned());
+/// frame.draw(&mut grid, area);
+///
+/// let list = KeyValueList::new([("Uptime", "3d"), ("Users", "42")]);
+/// list.draw(&mut grid, frame.inner(area));
+///
+/// // widgets of different types can be kept together
+/// let bars: Vec<Box<dyn Widget>> = vec![
+///     Box::new(ProgressBar::new(3, 4)),
+///     Box::new(Gauge::new(1, 4)),
+/// ];
+/// for (row, bar) in bars.iter().enumerate() {
+///     bar.draw(&mut grid, Region::new(Origin::new(0, 6 + row), 20, 1));
+/// }
+///
+/// # let connection = FakeConnection;
+/// connection.send_command(CharGridCommand { origin: Origin::ZERO, grid }).unwrap();
+/// ```
+pub trait Widget {
+    /// Draws the widget into the area of the grid.
+    fn draw(&self, grid: &mut dyn GridMut<char>, area: Region<Tiles>);
+
+    /// Like [`Self::draw`], but for a [`crate::Cp437Grid`].
+    #[cfg(feature = "cp437")]
+    fn draw_cp437(&self, grid: &mut dyn GridMut<u8>, area: Region<Tiles>) {
+        self.draw(&mut cp437::Cp437Cells(grid), area);
+    }
+}
+
+/// The axis along which a [`ProgressBar`] fills up or a [`crate::Layout`] places its panes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// a bar fills up from left to right, panes are placed from left to right and take the full height
+    #[default]
+    Horizontal,
+    /// a bar fills up from bottom to top, panes are placed from top to bottom and take the full width
+    Vertical,
+}
+
+/// Sets a cell relative to the area, if it is inside of the area.
+fn put(
+    grid: &mut dyn GridMut<char>,
+    area: Region<Tiles>,
+    x: usize,
+    y: usize,
+    char: char,
+) {
+    if x < area.width && y < area.height {
+        _ = grid.set_optional(area.origin.x + x, area.origin.y + y, char);
+    }
+}
+
+/// Writes text into a row relative to the area, cutting it after `max_width` cells.
+///
+/// returns: the amount of cells written
+fn put_str(
+    grid: &mut dyn GridMut<char>,
+    area: Region<Tiles>,
+    x: usize,
+    y: usize,
+    text: &str,
+    max_width: usize,
+) -> usize {
+    let cells = row_cells(text);
+    let count = cells.len().min(max_width);
+    for (offset, char) in cells.into_iter().take(count).enumerate() {
+        put(grid, area, x + offset, y, char);
+    }
+    count
+}
+
+#[cfg(feature = "cp437")]
+mod cp437 {
+    use crate::{
+        cp437::{char_to_cp437, cp437_to_char},
+        Grid, GridMut,
+    };
+
+    /// Converts characters to CP-437 when drawing.
+    pub(super) struct Cp437Cells<'g, G: ?Sized>(pub &'g mut G);
+
+    impl<G: GridMut<u8> + ?Sized> Grid<char> for Cp437Cells<'_, G> {
+        fn get_optional(&self, x: usize, y: usize) -> Option<char> {
+            self.0.get_optional(x, y).map(cp437_to_char)
+        }
+
+        fn width(&self) -> usize {
+            self.0.width()
+        }
+
+        fn height(&self) -> usize {
+            self.0.height()
+        }
+    }
+
+    impl<G: GridMut<u8> + ?Sized> GridMut<char> for Cp437Cells<'_, G> {
+        fn set_optional(&mut self, x: usize, y: usize, value: char) -> bool {
+            self.0.set_optional(x, y, char_to_cp437(value))
+        }
+
+        fn fill(&mut self, value: char) {
+            self.0.fill(char_to_cp437(value));
+        }
+    }
+}
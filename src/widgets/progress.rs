@@ -0,0 +1,176 @@
+use crate::{
+    containers::text_width,
+    widgets::{put, put_str},
+    GridMut, Orientation, Region, Tiles, Widget,
+};
+
+/// Cells that are partially filled, in quarters.
+const SHADES: [char; 4] = [' ', '░', '▒', '▓'];
+const FULL: char = '█';
+
+/// A bar filling up the area according to the progress, with a resolution of a quarter cell.
+///
+/// Completely filled cells are shown as █, partially filled cells as ░, ▒ or ▓.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProgressBar {
+    /// the current progress, values above `max` are shown as complete
+    pub value: usize,
+    /// the value at which the bar is full
+    pub max: usize,
+    /// the direction the bar fills up in
+    pub orientation: Orientation,
+}
+
+impl ProgressBar {
+    /// Creates a horizontal bar.
+    #[must_use]
+    pub fn new(value: usize, max: usize) -> Self {
+        Self {
+            value,
+            max,
+            orientation: Orientation::Horizontal,
+        }
+    }
+
+    /// The cell at position `index` of a bar of `length` cells, starting at the empty end.
+    fn cell(&self, index: usize, length: usize) -> char {
+        if self.max == 0 {
+            return SHADES[0];
+        }
+        let quarters = scale(self.value, self.max, length * 4);
+        match index.cmp(&(quarters / 4)) {
+            std::cmp::Ordering::Less => FULL,
+            std::cmp::Ordering::Equal => SHADES[quarters % 4],
+            std::cmp::Ordering::Greater => SHADES[0],
+        }
+    }
+}
+
+impl Widget for ProgressBar {
+    fn draw(&self, grid: &mut dyn GridMut<char>, area: Region<Tiles>) {
+        for y in 0..area.height {
+            for x in 0..area.width {
+                let char = match self.orientation {
+                    Orientation::Horizontal => self.cell(x, area.width),
+                    Orientation::Vertical => {
+                        self.cell(area.height - 1 - y, area.height)
+                    }
+                };
+                put(grid, area, x, y, char);
+            }
+        }
+    }
+}
+
+/// A horizontal [`ProgressBar`] with the percentage or a label shown in the middle.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Gauge {
+    /// the current progress, values above `max` are shown as complete
+    pub value: usize,
+    /// the value at which the gauge is full
+    pub max: usize,
+    /// shown instead of the percentage if set
+    pub label: Option<String>,
+}
+
+impl Gauge {
+    /// Creates a gauge showing the percentage.
+    #[must_use]
+    pub fn new(value: usize, max: usize) -> Self {
+        Self {
+            value,
+            max,
+            label: None,
+        }
+    }
+
+    /// The progress in percent, rounded down.
+    #[must_use]
+    pub fn percent(&self) -> usize {
+        if self.max == 0 {
+            return 0;
+        }
+        scale(self.value, self.max, 100)
+    }
+}
+
+/// Maps `value` from `0..=max` to `0..=to`, rounding down. Values above `max` are treated as `max`.
+///
+/// The intermediate product is computed in 128 bits, so large values do not overflow.
+fn scale(value: usize, max: usize, to: usize) -> usize {
+    let scaled = value.min(max) as u128 * to as u128 / max as u128;
+    usize::try_from(scaled).unwrap_or(usize::MAX)
+}
+
+impl Widget for Gauge {
+    fn draw(&self, grid: &mut dyn GridMut<char>, area: Region<Tiles>) {
+        ProgressBar::new(self.value, self.max).draw(grid, area);
+
+        let label = self
+            .label
+            .clone()
+            .unwrap_or_else(|| format!("{}%", self.percent()));
+        let width = text_width(&label).min(area.width);
+        let x = (area.width - width) / 2;
+        let y = area.height / 2;
+        put_str(grid, area, x, y, &label, width);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        CharGrid, CharGridExt, Gauge, Orientation, Origin, ProgressBar, Region,
+        Widget,
+    };
+
+    #[test]
+    fn horizontal() {
+        let mut grid = CharGrid::new(4, 1);
+        let area = Region::new(Origin::ZERO, 4, 1);
+        ProgressBar::new(0, 8).draw(&mut grid, area);
+        assert_eq!(String::from(&grid), "    ");
+        ProgressBar::new(3, 8).draw(&mut grid, area);
+        assert_eq!(String::from(&grid), "█▒  ");
+        ProgressBar::new(7, 8).draw(&mut grid, area);
+        assert_eq!(String::from(&grid), "███▒");
+        ProgressBar::new(9, 8).draw(&mut grid, area);
+        assert_eq!(String::from(&grid), "████");
+        ProgressBar::new(1, 0).draw(&mut grid, area);
+        assert_eq!(String::from(&grid), "    ");
+    }
+
+    #[test]
+    fn vertical() {
+        let mut grid = CharGrid::new(2, 3);
+        let mut bar = ProgressBar::new(7, 12);
+        bar.orientation = Orientation::Vertical;
+        bar.draw(&mut grid, Region::new(Origin::ZERO, 2, 3));
+        assert_eq!(String::from(grid), "  \n▓▓\n██");
+    }
+
+    #[test]
+    fn gauge() {
+        let mut grid = CharGrid::new(8, 3);
+        let gauge = Gauge::new(1, 2);
+        assert_eq!(gauge.percent(), 50);
+        gauge.draw(&mut grid, Region::new(Origin::ZERO, 8, 3));
+        assert_eq!(String::from(&grid), "████    \n██50%   \n████    ");
+
+        let mut gauge = Gauge::new(2, 2);
+        gauge.label = Some("done!".to_owned());
+        gauge.draw(&mut grid, Region::new(Origin::ZERO, 3, 1));
+        assert_eq!(grid.get_row_str(0), Some("don█    ".to_owned()));
+    }
+
+    #[test]
+    fn large_values() {
+        let gauge = Gauge::new(usize::MAX / 2, usize::MAX);
+        assert_eq!(gauge.percent(), 49);
+
+        let mut grid = CharGrid::new(4, 1);
+        ProgressBar::new(usize::MAX / 4, usize::MAX)
+            .draw(&mut grid, Region::new(Origin::ZERO, 4, 1));
+        assert_eq!(String::from(&grid), "▓   ");
+    }
+}
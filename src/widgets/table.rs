@@ -0,0 +1,128 @@
+use crate::{
+    widgets::{put, put_str},
+    GridMut, Region, Tiles, Widget,
+};
+
+/// Rows of cells in columns of fixed width, separated by │.
+///
+/// Cells are cut off if they are too long for their column.
+/// Rows that do not fit into the area are not shown.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Table {
+    /// the width of each column, without separators
+    pub widths: Vec<usize>,
+    /// shown above the rows, separated by a line
+    pub header: Option<Vec<String>>,
+    /// the cells of each row
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    /// Creates a table without header or rows.
+    #[must_use]
+    pub fn new(widths: impl Into<Vec<usize>>) -> Self {
+        Self {
+            widths: widths.into(),
+            header: None,
+            rows: Vec::new(),
+        }
+    }
+
+    /// Appends a row.
+    pub fn push_row(
+        &mut self,
+        cells: impl IntoIterator<Item = impl Into<String>>,
+    ) {
+        self.rows.push(cells.into_iter().map(Into::into).collect());
+    }
+
+    /// The amount of cells the table covers in a row, including separators.
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.widths.iter().sum::<usize>() + self.widths.len().saturating_sub(1)
+    }
+
+    fn draw_row(
+        &self,
+        grid: &mut dyn GridMut<char>,
+        area: Region<Tiles>,
+        y: usize,
+        cells: &[String],
+    ) {
+        let mut x = 0;
+        for (column, width) in self.widths.iter().enumerate() {
+            if column > 0 {
+                put(grid, area, x, y, '│');
+                x += 1;
+            }
+            let cell = cells.get(column).map_or("", String::as_str);
+            let written = put_str(grid, area, x, y, cell, *width);
+            for offset in written..*width {
+                put(grid, area, x + offset, y, ' ');
+            }
+            x += width;
+        }
+    }
+}
+
+impl Widget for Table {
+    fn draw(&self, grid: &mut dyn GridMut<char>, area: Region<Tiles>) {
+        let mut y = 0;
+        if let Some(header) = &self.header {
+            self.draw_row(grid, area, y, header);
+            let mut x = 0;
+            for (column, width) in self.widths.iter().enumerate() {
+                if column > 0 {
+                    put(grid, area, x, y + 1, '┼');
+                    x += 1;
+                }
+                for offset in 0..*width {
+                    put(grid, area, x + offset, y + 1, '─');
+                }
+                x += width;
+            }
+            y += 2;
+        }
+
+        for row in &self.rows {
+            if y >= area.height {
+                break;
+            }
+            self.draw_row(grid, area, y, row);
+            y += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CharGrid, GridMut, Origin, Region, Table, Widget};
+
+    #[test]
+    fn with_header() {
+        let mut table = Table::new([4, 2]);
+        table.header = Some(vec!["Name".to_owned(), "Ok".to_owned()]);
+        table.push_row(["CCCB", "ja"]);
+        table.push_row(["Servicepoint", "yes"]);
+        table.push_row(["hidden", "-"]);
+        assert_eq!(table.width(), 7);
+
+        let mut grid = CharGrid::new(8, 4);
+        grid.fill('.');
+        table.draw(&mut grid, Region::new(Origin::ZERO, 7, 4));
+        assert_eq!(
+            String::from(grid),
+            "Name│Ok.\n────┼──.\nCCCB│ja.\nServ│ye."
+        );
+    }
+
+    #[test]
+    fn missing_cells() {
+        let mut table = Table::new(vec![1, 1, 1]);
+        table.push_row(["a"]);
+        let mut grid = CharGrid::new(5, 1);
+        grid.fill('.');
+        table.draw(&mut grid, Region::new(Origin::ZERO, 4, 1));
+        assert_eq!(String::from(grid), "a│ │.");
+    }
+}